@@ -0,0 +1,80 @@
+//! times parsing and each part separately for every day, on the puzzle examples in
+//! `fixtures/` and on whichever real inputs are present in `inputs/`.
+//!
+//! Criterion writes its estimates as JSON under `target/criterion/`. To compare two runs,
+//! save the first with `cargo bench -- --save-baseline before`, then run
+//! `cargo bench -- --baseline before` after the change.
+
+use std::{
+    hint::black_box,
+    path::{Path, PathBuf},
+};
+
+use advent_of_code_2025::{
+    days::{
+        day01::Day01, day02::Day02, day03::Day03, day04::Day04, day05::Day05, day06::Day06,
+        day07::Day07, day08::Day08, day09::Day09, day10::Day10, day11::Day11,
+    },
+    inputs::{example_path, input_path},
+    solution::Solution,
+};
+use criterion::{Criterion, criterion_group, criterion_main};
+
+fn dir(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(name)
+}
+
+/// benchmarks one day on one kind of input, given the path to each part's input
+fn bench<S: Solution>(c: &mut Criterion, day: u8, kind: &str, paths: [PathBuf; 2]) {
+    let mut group = c.benchmark_group(format!("day{day:02}/{kind}"));
+    // some parts take long enough that criterion's default of 100 samples is impractical
+    group.sample_size(10);
+    let solve = [
+        |input: &S::Input| S::part1(input).to_string(),
+        |input: &S::Input| S::part2(input).to_string(),
+    ];
+    for (part, solve) in (1..).zip(solve) {
+        let path = &paths[part - 1];
+        let Ok(input) = std::fs::read_to_string(path) else {
+            continue;
+        };
+        // only time parsing again if this part has its own input
+        if part == 1 || paths[0] != paths[1] {
+            group.bench_function(format!("parse{part}"), |b| {
+                b.iter(|| S::parse(black_box(&input)).unwrap())
+            });
+        }
+        let parsed = S::parse(&input).unwrap();
+        group.bench_function(format!("part{part}"), |b| {
+            b.iter(|| solve(black_box(&parsed)))
+        });
+    }
+    group.finish();
+}
+
+/// `E` solves the example, and `S` the real input; they differ only where the
+/// example uses different parameters than the real puzzle
+fn bench_day<E: Solution, S: Solution>(c: &mut Criterion, day: u8) {
+    let fixtures = dir("fixtures");
+    let examples = [1, 2].map(|part| example_path(&fixtures, day, part));
+    bench::<E>(c, day, "example", examples);
+    let input = input_path(&dir("inputs"), day);
+    bench::<S>(c, day, "input", [input.clone(), input]);
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<Day01, Day01>(c, 1);
+    bench_day::<Day02, Day02>(c, 2);
+    bench_day::<Day03, Day03>(c, 3);
+    bench_day::<Day04, Day04>(c, 4);
+    bench_day::<Day05, Day05>(c, 5);
+    bench_day::<Day06, Day06>(c, 6);
+    bench_day::<Day07, Day07>(c, 7);
+    bench_day::<Day08<10>, Day08>(c, 8);
+    bench_day::<Day09, Day09>(c, 9);
+    bench_day::<Day10, Day10>(c, 10);
+    bench_day::<Day11, Day11>(c, 11);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,86 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use crate::parse::{ParseError, lines};
+
+/// known answers, keyed by day and part, read from and written to a file with
+/// one `<day> <part> <answer>` line each. Blank lines and `#` comments are skipped.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u8, usize), String>);
+
+impl Answers {
+    pub fn get(&self, day: u8, part: usize) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    /// returns the answer that was replaced, if any
+    pub fn insert(&mut self, day: u8, part: usize, answer: String) -> Option<String> {
+        self.0.insert((day, part), answer)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u8, usize, &str)> {
+        self.0
+            .iter()
+            .map(|(&(day, part), answer)| (day, part, answer.as_str()))
+    }
+}
+
+impl FromStr for Answers {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Self::default();
+        for line in lines(s) {
+            let text = line.text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            let mut fields = text.split_whitespace();
+            let mut field = |expected: &str| {
+                fields
+                    .next()
+                    .ok_or_else(|| line.error(&text[text.len()..], expected))
+            };
+            let day = line.parse(field("a day")?, "a day")?;
+            let part = line.parse(field("a part")?, "a part")?;
+            let answer = field("an answer")?;
+            if let Some(extra) = fields.next() {
+                return Err(line.error(extra, "the end of the line"));
+            }
+            answers.insert(day, part, answer.to_owned());
+        }
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day part answer")?;
+        for (day, part, answer) in self.iter() {
+            writeln!(f, "{day} {part} {answer}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Answers;
+
+    #[test]
+    fn test_round_trip() {
+        let answers: Answers = "# comment\n\n8 2 25272\n1 1 3\n".parse().unwrap();
+        assert_eq!(answers.get(1, 1), Some("3"));
+        assert_eq!(answers.get(8, 2), Some("25272"));
+        assert_eq!(answers.get(8, 1), None);
+        assert_eq!(answers.to_string(), "# day part answer\n1 1 3\n8 2 25272\n");
+        assert_eq!(answers.to_string().parse::<Answers>(), Ok(answers));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = "1 1 3\n1 x 6\n".parse::<Answers>().unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 3, "x"));
+        let err = "1 1\n".parse::<Answers>().unwrap_err();
+        assert_eq!(err.expected, "an answer");
+    }
+}
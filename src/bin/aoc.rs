@@ -0,0 +1,565 @@
+use std::{
+    io::{IsTerminal, Read},
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use advent_of_code_2025::{
+    answers::Answers,
+    days::{
+        self, DAYS, Day,
+        day01::Day01,
+        day02::{
+            self, DECIMAL, Id, Repetitions, count_over, merge_ranges, parse_ranges, repeateds,
+            sum_over, to_str_radix,
+        },
+        day03::{SEQ_LEN, joltage, parse_banks},
+    },
+    dial::{DIAL_SIZE, DIAL_START, Dial, Rotation},
+    inputs::input_path,
+    parse::ParseError,
+    solution::Solution,
+};
+use clap::{
+    Args, Parser, Subcommand, ValueEnum,
+    builder::{RangedI64ValueParser, RangedU64ValueParser},
+};
+use itertools::Itertools;
+use num_bigint::BigUint;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2025 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// solve one day (both parts unless a part is given), or every day with --all
+    Run {
+        #[arg(
+            required_unless_present = "all",
+            conflicts_with = "all",
+            value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64),
+        )]
+        day: Option<u8>,
+        #[arg(conflicts_with = "all", value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        #[arg(long, conflicts_with = "input")]
+        all: bool,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// list the days and parts that have solutions
+    List,
+    /// solve every day with an input in --inputs, and compare with the answers in --answers
+    Verify {
+        #[command(flatten)]
+        inputs: InputsDir,
+        /// file of known answers, one `<day> <part> <answer>` per line
+        #[arg(long, default_value = "answers.txt")]
+        answers: PathBuf,
+        /// add answers that weren't in --answers yet to it; only do this once they're confirmed correct
+        #[arg(long)]
+        record: bool,
+    },
+    /// turn a dial through day 1's rotations, counting how often it points at each target
+    Dial {
+        /// number of positions on the dial
+        #[arg(long, default_value_t = DIAL_SIZE, value_parser = RangedI64ValueParser::<isize>::new().range(1..))]
+        size: isize,
+        /// position the dial starts at
+        #[arg(long, default_value_t = DIAL_START, allow_negative_numbers = true)]
+        start: isize,
+        /// positions to count, which may be given more than once
+        #[arg(long = "target", default_values_t = [0], allow_negative_numbers = true)]
+        targets: Vec<isize>,
+        /// print every rotation, with its hits on each target and the running totals
+        #[arg(long)]
+        trace: bool,
+        /// print the trace as CSV
+        #[arg(long, requires = "trace")]
+        csv: bool,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// count and sum the IDs in day 2's ranges that repeat a sequence of digits
+    Ids {
+        /// base the IDs are written in, from 2 to 36
+        #[arg(long, default_value_t = DECIMAL, value_parser = clap::value_parser!(u32).range(2..=36))]
+        base: u32,
+        /// integer type to sum the IDs in
+        #[arg(long, value_enum, default_value_t = IdType::U128)]
+        int: IdType,
+        /// list each repeating ID in the ranges instead, with its period and repetitions
+        #[arg(long)]
+        list: bool,
+        /// list only the IDs that repeat a sequence exactly twice
+        #[arg(long, requires = "list")]
+        twice: bool,
+        /// merge overlapping ranges first, so IDs in more than one are counted once
+        #[arg(long)]
+        dedupe_ranges: bool,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// sum the largest joltage each of day 3's banks makes, turning on any number of batteries
+    Joltage {
+        /// number of batteries to turn on in each bank
+        #[arg(long, default_value_t = SEQ_LEN, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+        batteries: usize,
+        /// print every bank and its joltage, highlighting the batteries turned on
+        #[arg(long)]
+        explain: bool,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum IdType {
+    U64,
+    U128,
+    /// arbitrary precision
+    Big,
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// puzzle input file, or `-` for stdin; defaults to the day's file in --inputs
+    #[arg(long)]
+    input: Option<PathBuf>,
+    #[command(flatten)]
+    inputs: InputsDir,
+}
+
+#[derive(Args)]
+struct InputsDir {
+    /// directory of puzzle inputs named by two-digit day, like `08.txt`
+    #[arg(long = "inputs", value_name = "INPUTS", default_value = "inputs")]
+    dir: PathBuf,
+}
+
+impl InputArgs {
+    fn read(&self, day: u8) -> Result<String, Error> {
+        match &self.input {
+            Some(path) => read_input(path),
+            None => read_input(&input_path(&self.inputs.dir, day)),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum Error {
+    #[error("Could not read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Could not write {path}: {source}")]
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Day {day} part {part}: {source}")]
+    Solve {
+        day: u8,
+        part: usize,
+        source: ParseError,
+    },
+    #[error("Day {day}: {source}")]
+    Parse { day: u8, source: ParseError },
+    #[error("Invalid answers file {path}: {source}")]
+    Answers { path: PathBuf, source: ParseError },
+    #[error(transparent)]
+    Overflow(#[from] day02::Overflow),
+    #[error("{0} of the answers did not verify")]
+    Unverified(usize),
+}
+
+struct Row {
+    day: u8,
+    part: usize,
+    answer: String,
+    elapsed: Duration,
+}
+
+fn read_input(path: &Path) -> Result<String, Error> {
+    let res = if path == Path::new("-") {
+        let mut buf = String::new();
+        std::io::stdin().read_to_string(&mut buf).map(|_| buf)
+    } else {
+        std::fs::read_to_string(path)
+    };
+    res.map_err(|source| Error::Io {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+fn solve(day: &Day, parts: &[usize], input: &str) -> Result<Vec<Row>, Error> {
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = day.parts[part - 1](input).map_err(|source| Error::Solve {
+                day: day.number,
+                part,
+                source,
+            })?;
+            Ok(Row {
+                day: day.number,
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            })
+        })
+        .collect()
+}
+
+/// prints `rows`, the first of which is a header, as CSV or as right-aligned columns
+fn print_columns(rows: &[Vec<String>], csv: bool) {
+    if csv {
+        for row in rows {
+            println!("{}", row.join(","));
+        }
+        return;
+    }
+    let widths = (0..rows.first().map_or(0, Vec::len))
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].len())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect_vec();
+    for row in rows {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:>width$}"));
+        println!("{}", cells.format("  "));
+    }
+}
+
+fn trace_dial(dial: &Dial, rotations: &[Rotation], csv: bool) {
+    let mut header = vec![
+        "rotation".to_owned(),
+        "before".to_owned(),
+        "after".to_owned(),
+    ];
+    for target in dial.targets() {
+        header.extend(
+            ["landed", "passed", "total landed", "total passed"].map(|h| format!("{h} {target}")),
+        );
+    }
+    let turns = dial.trace(rotations).map(|turn| {
+        let mut row = vec![
+            turn.rotation.to_string(),
+            turn.before.to_string(),
+            turn.after.to_string(),
+        ];
+        for (hits, totals) in turn.hits.values().zip(turn.totals.values()) {
+            row.extend(
+                [hits.landings, hits.passes, totals.landings, totals.passes].map(|n| n.to_string()),
+            );
+        }
+        row
+    });
+    print_columns(&[header].into_iter().chain(turns).collect_vec(), csv);
+}
+
+/// day 2's ranges, warning about reversed ones and merging them if asked to
+fn read_ranges<T: Id>(
+    input: &str,
+    base: u32,
+    merge: bool,
+) -> Result<Vec<RangeInclusive<T>>, Error> {
+    let ranges =
+        parse_ranges::<T>(input, base).map_err(|source| Error::Parse { day: 2, source })?;
+    for range in ranges.iter().filter(|range| range.is_empty()) {
+        eprintln!(
+            "ignoring range {}-{}: it is reversed, so empty",
+            to_str_radix(range.start(), base),
+            to_str_radix(range.end(), base)
+        );
+    }
+    Ok(if merge { merge_ranges(&ranges) } else { ranges })
+}
+
+fn sum_ids<T: Id>(ranges: &[RangeInclusive<T>], base: u32) -> Result<(), Error> {
+    let mut rows = vec![["repeated", "count", "sum"].map(str::to_owned).to_vec()];
+    for (name, repetitions) in [
+        ("twice", Repetitions::Twice),
+        ("at least twice", Repetitions::AtLeastTwice),
+    ] {
+        let count: T = count_over(ranges, base, repetitions)?;
+        let sum: T = sum_over(ranges, base, repetitions)?;
+        rows.push(vec![name.to_owned(), count.to_string(), sum.to_string()]);
+    }
+    print_columns(&rows, false);
+    Ok(())
+}
+
+fn list_ids<T: Id>(
+    ranges: &[RangeInclusive<T>],
+    base: u32,
+    repetitions: Repetitions,
+) -> Result<(), Error> {
+    let mut rows = vec![["id", "period", "repetitions"].map(str::to_owned).to_vec()];
+    for range in ranges {
+        rows.extend(repeateds(range, base, repetitions)?.map(|repeated| {
+            vec![
+                to_str_radix(&repeated.id, base),
+                repeated.period.to_string(),
+                repeated.repetitions.to_string(),
+            ]
+        }));
+    }
+    print_columns(&rows, false);
+    Ok(())
+}
+
+/// lists day 2's invalid IDs with `repetitions`, or counts and sums them if it's `None`
+fn ids<T: Id>(input: &str, base: u32, list: Option<Repetitions>, merge: bool) -> Result<(), Error> {
+    let ranges = read_ranges::<T>(input, base, merge)?;
+    match list {
+        Some(repetitions) => list_ids(&ranges, base, repetitions),
+        None => sum_ids(&ranges, base),
+    }
+}
+
+/// writes a bank's digits with the batteries at `positions` in bold green, or in brackets
+/// if `color` is off
+fn highlight(bank: &[u8], positions: &[usize], color: bool) -> String {
+    let (on, off) = match color {
+        true => ("\x1b[1;32m", "\x1b[0m"),
+        false => ("[", "]"),
+    };
+    let mut positions = positions.iter().peekable();
+    let mut highlighted = String::new();
+    for (chosen, run) in &bank
+        .iter()
+        .enumerate()
+        .chunk_by(|&(i, _)| positions.next_if_eq(&&i).is_some())
+    {
+        let digits: String = run.map(|(_, digit)| digit.to_string()).collect();
+        match chosen {
+            true => highlighted.extend([on, &digits, off]),
+            false => highlighted.push_str(&digits),
+        }
+    }
+    highlighted
+}
+
+fn print_table(rows: &[Row]) {
+    let answer_width = rows
+        .iter()
+        .map(|row| row.answer.len())
+        .chain(["answer".len()])
+        .max()
+        .unwrap_or_default();
+    println!("day  part  {:answer_width$}  time", "answer");
+    for row in rows {
+        println!(
+            "{:>3}  {:>4}  {:answer_width$}  {:.2?}",
+            row.day, row.part, row.answer, row.elapsed
+        );
+    }
+}
+
+fn verify(inputs: &Path, answers_path: &Path, record: bool) -> Result<(), Error> {
+    let mut answers = if answers_path.exists() {
+        read_input(answers_path)?
+            .parse::<Answers>()
+            .map_err(|source| Error::Answers {
+                path: answers_path.to_owned(),
+                source,
+            })?
+    } else {
+        Answers::default()
+    };
+    let mut unverified = 0;
+    let mut new = vec![];
+    for day in &DAYS {
+        let path = input_path(inputs, day.number);
+        if !path.exists() {
+            println!("day {:>2}: missing input {}", day.number, path.display());
+            continue;
+        }
+        let input = read_input(&path)?;
+        let rows = match solve(day, &[1, 2], &input) {
+            Ok(rows) => rows,
+            Err(err) => {
+                unverified += 1;
+                println!("day {:>2}: FAILED {err}", day.number);
+                continue;
+            }
+        };
+        for row in rows {
+            let Row {
+                day, part, answer, ..
+            } = row;
+            match answers.get(day, part) {
+                Some(expected) if expected == answer => {
+                    println!("day {day:>2} part {part}: ok {answer}")
+                }
+                Some(expected) => {
+                    unverified += 1;
+                    println!("day {day:>2} part {part}: MISMATCH {answer}, expected {expected}")
+                }
+                None => {
+                    println!("day {day:>2} part {part}: new {answer}");
+                    new.push((day, part, answer));
+                }
+            }
+        }
+    }
+    if record && !new.is_empty() {
+        println!(
+            "recording {} new answers in {}",
+            new.len(),
+            answers_path.display()
+        );
+        for (day, part, answer) in new {
+            answers.insert(day, part, answer);
+        }
+        std::fs::write(answers_path, answers.to_string()).map_err(|source| Error::Write {
+            path: answers_path.to_owned(),
+            source,
+        })?;
+    }
+    match unverified {
+        0 => Ok(()),
+        n => Err(Error::Unverified(n)),
+    }
+}
+
+fn run(command: Command) -> Result<(), Error> {
+    match command {
+        Command::List => {
+            for day in &DAYS {
+                println!(
+                    "day {:>2}: parts {}",
+                    day.number,
+                    (1..=day.parts.len()).join(", ")
+                );
+            }
+        }
+        Command::Run {
+            day: Some(day),
+            part,
+            input,
+            ..
+        } => {
+            let day = days::day(day).expect("day within the parsed range");
+            let input = input.read(day.number)?;
+            let parts = match part {
+                Some(part) => vec![usize::from(part)],
+                None => vec![1, 2],
+            };
+            print_table(&solve(day, &parts, &input)?);
+        }
+        Command::Run {
+            day: None, input, ..
+        } => {
+            let mut rows = vec![];
+            for day in &DAYS {
+                let path = input_path(&input.inputs.dir, day.number);
+                if !path.exists() {
+                    eprintln!(
+                        "skipping day {}: no input at {}",
+                        day.number,
+                        path.display()
+                    );
+                    continue;
+                }
+                let input = read_input(&path)?;
+                rows.extend(solve(day, &[1, 2], &input)?);
+            }
+            print_table(&rows);
+        }
+        Command::Verify {
+            inputs,
+            answers,
+            record,
+        } => verify(&inputs.dir, &answers, record)?,
+        Command::Dial {
+            size,
+            start,
+            targets,
+            trace,
+            csv,
+            input,
+        } => {
+            let rotations =
+                Day01::parse(&input.read(1)?).map_err(|source| Error::Parse { day: 1, source })?;
+            let dial = Dial::new(size, start, targets);
+            if trace {
+                trace_dial(&dial, &rotations, csv);
+                return Ok(());
+            }
+            println!("target  landings  passes");
+            for (target, hits) in dial.count(&rotations) {
+                println!("{target:>6}  {:>8}  {:>6}", hits.landings, hits.passes);
+            }
+        }
+        Command::Ids {
+            base,
+            int,
+            list,
+            twice,
+            dedupe_ranges,
+            input,
+        } => {
+            let input = input.read(2)?;
+            let repetitions = match twice {
+                true => Repetitions::Twice,
+                false => Repetitions::AtLeastTwice,
+            };
+            let list = list.then_some(repetitions);
+            match int {
+                IdType::U64 => ids::<u64>(&input, base, list, dedupe_ranges)?,
+                IdType::U128 => ids::<u128>(&input, base, list, dedupe_ranges)?,
+                IdType::Big => ids::<BigUint>(&input, base, list, dedupe_ranges)?,
+            }
+        }
+        Command::Joltage {
+            batteries,
+            explain,
+            input,
+        } => {
+            let input = input.read(3)?;
+            let banks =
+                parse_banks(&input, batteries).map_err(|source| Error::Parse { day: 3, source })?;
+            let mut total = BigUint::ZERO;
+            let color = std::io::stdout().is_terminal();
+            for bank in &banks {
+                let chosen = joltage(bank, batteries).expect("bank is long enough");
+                if explain {
+                    println!(
+                        "{}  {}",
+                        highlight(bank, &chosen.positions, color),
+                        chosen.value
+                    );
+                }
+                total += chosen.value;
+            }
+            println!("{total}");
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
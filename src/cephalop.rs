@@ -0,0 +1,28 @@
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cephalop {
+    Add,
+    Multiply,
+}
+
+impl FromStr for Cephalop {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Self::Add),
+            "*" => Ok(Self::Multiply),
+            _ => Err(format!("Expected + or *, got {s:?}")),
+        }
+    }
+}
+
+impl Cephalop {
+    pub fn reduce(&self, iter: impl Iterator<Item = usize>) -> usize {
+        match self {
+            Cephalop::Add => iter.sum(),
+            Cephalop::Multiply => iter.product(),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{
+    dial::{Dial, Rotation},
+    parse::{ParseError, lines},
+    solution::Solution,
+};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<Rotation>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        lines(input)
+            .filter(|line| !line.text.trim().is_empty())
+            .flat_map(|line| {
+                line.text.split(',').map(move |field| {
+                    Rotation::parse_spanned(field)
+                        .map_err(|(err, found)| line.error(found, err.expected()))
+                })
+            })
+            .collect()
+    }
+
+    fn part1(rotations: &Self::Input) -> usize {
+        Dial::default().count(rotations)[&0].landings
+    }
+
+    fn part2(rotations: &Self::Input) -> usize {
+        Dial::default().count(rotations)[&0].passes
+    }
+}
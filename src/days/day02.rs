@@ -0,0 +1,564 @@
+use std::{fmt::Display, ops::RangeInclusive};
+
+use itertools::Itertools;
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::{CheckedAdd, CheckedMul, FromPrimitive, Num, ToPrimitive};
+
+use crate::{
+    parse::{Line, ParseError, lines},
+    solution::Solution,
+};
+
+/// the base the puzzle's IDs are written in
+pub const DECIMAL: u32 = 10;
+
+/// unsigned integer types that IDs can be, from `u64` to `num_bigint::BigUint`
+pub trait Id:
+    Clone + Ord + Display + Num + Integer + CheckedAdd + CheckedMul + FromPrimitive + ToPrimitive
+{
+}
+
+impl<
+    T: Clone + Ord + Display + Num + Integer + CheckedAdd + CheckedMul + FromPrimitive + ToPrimitive,
+> Id for T
+{
+}
+
+/// arithmetic on IDs that didn't fit in their type
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("These IDs overflow {0}")]
+pub struct Overflow(&'static str);
+
+impl Overflow {
+    fn of<T>() -> Self {
+        Self(std::any::type_name::<T>())
+    }
+}
+
+fn add<T: Id>(a: &T, b: &T) -> Result<T, Overflow> {
+    a.checked_add(b).ok_or_else(Overflow::of::<T>)
+}
+
+fn mul<T: Id>(a: &T, b: &T) -> Result<T, Overflow> {
+    a.checked_mul(b).ok_or_else(Overflow::of::<T>)
+}
+
+fn pow<T: Id>(radix: u32, exp: u32) -> Result<T, Overflow> {
+    let radix = T::from_u32(radix).ok_or_else(Overflow::of::<T>)?;
+    (0..exp).try_fold(T::one(), |acc, _| mul(&acc, &radix))
+}
+
+/// 1 for 0, like its usual written form
+fn number_of_digits<T: Id>(x: &T, radix: u32) -> Result<u32, Overflow> {
+    let radix = T::from_u32(radix).ok_or_else(Overflow::of::<T>)?;
+    let mut n_digits = 1;
+    let mut rest = x.clone() / radix.clone();
+    while !rest.is_zero() {
+        n_digits += 1;
+        rest = rest / radix.clone();
+    }
+    Ok(n_digits)
+}
+
+/// how many times an ID must repeat a sequence of digits to be invalid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Repetitions {
+    /// as in part 1
+    Twice,
+    /// as in part 2
+    AtLeastTwice,
+}
+
+/// an invalid ID, made of a sequence of digits repeated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Repeated<T> {
+    pub id: T,
+    /// number of digits in the sequence; the shortest sequence, unless repeating exactly
+    /// twice is what made the ID invalid
+    pub period: u32,
+    pub repetitions: u32,
+}
+
+/// the `n_digits`-digit numbers in a range made of some sequence of `seq_len` digits
+/// repeated, where `seq_len` divides `n_digits`
+struct Repeats<T> {
+    /// repeating a sequence multiplies it by something like 10101 in base `radix`
+    multiplier: T,
+    /// never empty
+    sequences: RangeInclusive<T>,
+    seq_len: u32,
+    repetitions: u32,
+}
+
+impl<T: Id> Repeats<T> {
+    /// `None` if there are no such numbers in the range
+    fn new(
+        range: &RangeInclusive<T>,
+        n_digits: u32,
+        seq_len: u32,
+        radix: u32,
+    ) -> Result<Option<Self>, Overflow> {
+        let repetitions = n_digits / seq_len;
+        let multiplier =
+            (0..repetitions).try_fold(T::zero(), |acc, i| add(&acc, &pow(radix, i * seq_len)?))?;
+        let lo = range
+            .start()
+            .div_ceil(&multiplier)
+            .max(pow(radix, seq_len - 1)?);
+        let hi =
+            (range.end().clone() / multiplier.clone()).min(pow::<T>(radix, seq_len)? - T::one());
+        Ok((lo <= hi).then_some(Self {
+            multiplier,
+            sequences: lo..=hi,
+            seq_len,
+            repetitions,
+        }))
+    }
+
+    fn count(&self) -> T {
+        let (lo, hi) = (self.sequences.start(), self.sequences.end());
+        hi.clone() - lo.clone() + T::one()
+    }
+
+    fn sum(&self) -> Result<T, Overflow> {
+        // the multiplier times the arithmetic series of the sequences, halving whichever
+        // factor is even
+        let count = self.count();
+        let ends = add(self.sequences.start(), self.sequences.end())?;
+        let two = T::one() + T::one();
+        let series = if count.is_even() {
+            mul(&(count / two), &ends)?
+        } else {
+            mul(&count, &(ends / two))?
+        };
+        mul(&self.multiplier, &series)
+    }
+
+    /// in ascending order
+    fn into_iter(self) -> impl Iterator<Item = Repeated<T>> {
+        let Self {
+            multiplier,
+            sequences,
+            seq_len,
+            repetitions,
+        } = self;
+        let (lo, hi) = sequences.into_inner();
+        std::iter::successors(Some(lo), move |seq| {
+            (*seq < hi).then(|| seq.clone() + T::one())
+        })
+        .map(move |seq| Repeated {
+            id: seq * multiplier.clone(),
+            period: seq_len,
+            repetitions,
+        })
+    }
+}
+
+/// the Möbius function: 0 if a prime divides `n` more than once, otherwise 1 or -1
+/// for an even or odd number of prime factors
+fn mobius(mut n: u32) -> i8 {
+    let mut mu = 1;
+    let mut p = 2;
+    while p * p <= n {
+        if n.is_multiple_of(p) {
+            n /= p;
+            if n.is_multiple_of(p) {
+                return 0;
+            }
+            mu = -mu;
+        }
+        p += 1;
+    }
+    if n > 1 { -mu } else { mu }
+}
+
+/// the digit counts of the numbers in the range
+fn digit_counts<T: Id>(
+    range: &RangeInclusive<T>,
+    radix: u32,
+) -> Result<RangeInclusive<u32>, Overflow> {
+    Ok(number_of_digits(range.start(), radix)?..=number_of_digits(range.end(), radix)?)
+}
+
+/// the sequence lengths that make numbers of `n_digits` digits invalid, each with its weight
+/// in the inclusion-exclusion that counts every invalid number once
+fn weighted_seq_lens(n_digits: u32, repetitions: Repetitions) -> Vec<(u32, i8)> {
+    match repetitions {
+        Repetitions::Twice if n_digits.is_multiple_of(2) => vec![(n_digits / 2, 1)],
+        Repetitions::Twice => vec![],
+        // every number is its own digits repeated once, so Möbius inversion over the
+        // sequence lengths dividing `n_digits` leaves those with a shorter sequence,
+        // counting each once however many sequence lengths make it
+        Repetitions::AtLeastTwice => (1..n_digits)
+            .filter(|&len| n_digits.is_multiple_of(len))
+            .map(|len| (len, -mobius(n_digits / len)))
+            .filter(|&(_, weight)| weight != 0)
+            .collect(),
+    }
+}
+
+/// totals `measure` over the invalid IDs in the range, without listing them
+fn total<T: Id>(
+    range: &RangeInclusive<T>,
+    radix: u32,
+    repetitions: Repetitions,
+    measure: fn(&Repeats<T>) -> Result<T, Overflow>,
+) -> Result<T, Overflow> {
+    let mut total = T::zero();
+    let mut overcounted = T::zero();
+    for n_digits in digit_counts(range, radix)? {
+        for (seq_len, weight) in weighted_seq_lens(n_digits, repetitions) {
+            let Some(repeats) = Repeats::new(range, n_digits, seq_len, radix)? else {
+                continue;
+            };
+            let measured = measure(&repeats)?;
+            match weight {
+                1 => total = add(&total, &measured)?,
+                _ => overcounted = add(&overcounted, &measured)?,
+            }
+        }
+    }
+    Ok(total - overcounted)
+}
+
+/// sum of the invalid IDs in the range, written in base `radix`
+pub fn sum_repeateds<T: Id>(
+    range: &RangeInclusive<T>,
+    radix: u32,
+    repetitions: Repetitions,
+) -> Result<T, Overflow> {
+    total(range, radix, repetitions, Repeats::sum)
+}
+
+/// number of invalid IDs in the range, written in base `radix`
+pub fn count_repeateds<T: Id>(
+    range: &RangeInclusive<T>,
+    radix: u32,
+    repetitions: Repetitions,
+) -> Result<T, Overflow> {
+    total(range, radix, repetitions, |repeats| Ok(repeats.count()))
+}
+
+/// the invalid IDs in the range, written in base `radix`, in ascending order
+pub fn repeateds<T: Id>(
+    range: &RangeInclusive<T>,
+    radix: u32,
+    repetitions: Repetitions,
+) -> Result<impl Iterator<Item = Repeated<T>>, Overflow> {
+    let mut by_n_digits = vec![];
+    for n_digits in digit_counts(range, radix)? {
+        let mut all_repeats = vec![];
+        // every sequence length, not only the weighted ones, to find each ID's shortest
+        let seq_lens = match repetitions {
+            Repetitions::Twice => weighted_seq_lens(n_digits, repetitions),
+            Repetitions::AtLeastTwice => (1..n_digits)
+                .filter(|&len| n_digits.is_multiple_of(len))
+                .map(|len| (len, 0))
+                .collect(),
+        };
+        for (seq_len, _) in seq_lens {
+            all_repeats.extend(Repeats::new(range, n_digits, seq_len, radix)?);
+        }
+        by_n_digits.push(all_repeats);
+    }
+    Ok(by_n_digits
+        .into_iter()
+        .flat_map(|all_repeats| {
+            all_repeats
+                .into_iter()
+                .map(Repeats::into_iter)
+                .kmerge_by(|a, b| (&a.id, a.period) < (&b.id, b.period))
+        })
+        // several sequence lengths can make the same ID, and the shortest comes first
+        .dedup_by(|a, b| a.id == b.id))
+}
+
+/// parses comma-separated ranges like `11-22`, with bounds written in base `radix`
+pub fn parse_ranges<T: Id>(input: &str, radix: u32) -> Result<Vec<RangeInclusive<T>>, ParseError> {
+    let expected = format!("a base {radix} number");
+    let parse_bound = |line: Line, bound| {
+        T::from_str_radix(bound, radix).map_err(|_| line.error(bound, &expected))
+    };
+    lines(input)
+        .filter(|line| !line.text.trim().is_empty())
+        .flat_map(|line| {
+            line.text.trim().split(',').map(move |range_str| {
+                let (first_str, last_str) = range_str
+                    .split_once('-')
+                    .ok_or_else(|| line.error(range_str, "a range like 11-22"))?;
+                Ok(parse_bound(line, first_str)?..=parse_bound(line, last_str)?)
+            })
+        })
+        .collect()
+}
+
+/// writes an ID in base `radix`, as [`parse_ranges`] reads it, with lowercase letters for
+/// digits past 9
+pub fn to_str_radix<T: Id>(id: &T, radix: u32) -> String {
+    let radix_t = T::from_u32(radix).expect("radix fits in any ID type");
+    let mut digits = vec![];
+    let mut rest = id.clone();
+    loop {
+        let (quotient, digit) = rest.div_rem(&radix_t);
+        let digit = digit.to_u32().expect("digit is below the radix");
+        digits.push(char::from_digit(digit, radix).expect("radix is at most 36"));
+        if quotient.is_zero() {
+            break;
+        }
+        rest = quotient;
+    }
+    digits.iter().rev().collect()
+}
+
+/// merges overlapping and adjacent ranges, in ascending order, so an ID in more than one of
+/// them is counted once; reversed ranges are empty, and dropped. Sorts the ranges and sweeps
+/// through them, so it works for any ID type, including `BigUint` and ranges that end at a
+/// type's largest value.
+pub fn merge_ranges<T: Id>(ranges: &[RangeInclusive<T>]) -> Vec<RangeInclusive<T>> {
+    let mut sorted = ranges
+        .iter()
+        .filter(|range| !range.is_empty())
+        .cloned()
+        .collect::<Vec<_>>();
+    sorted.sort_by(|a, b| a.start().cmp(b.start()));
+    let mut merged: Vec<RangeInclusive<T>> = vec![];
+    for range in sorted {
+        match merged.last_mut() {
+            // overlapping or adjacent, unless the last range ends at the type's largest value
+            Some(last)
+                if last
+                    .end()
+                    .checked_add(&T::one())
+                    .is_none_or(|next| *range.start() <= next) =>
+            {
+                if range.end() > last.end() {
+                    *last = last.start().clone()..=range.end().clone();
+                }
+            }
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// number of invalid IDs in all of the ranges
+pub fn count_over<T: Id>(
+    ranges: &[RangeInclusive<T>],
+    radix: u32,
+    repetitions: Repetitions,
+) -> Result<T, Overflow> {
+    ranges.iter().try_fold(T::zero(), |count, range| {
+        add(&count, &count_repeateds(range, radix, repetitions)?)
+    })
+}
+
+/// sum of the invalid IDs in all of the ranges
+pub fn sum_over<T: Id>(
+    ranges: &[RangeInclusive<T>],
+    radix: u32,
+    repetitions: Repetitions,
+) -> Result<T, Overflow> {
+    ranges.iter().try_fold(T::zero(), |sum, range| {
+        add(&sum, &sum_repeateds(range, radix, repetitions)?)
+    })
+}
+
+/// IDs are arbitrary precision, so no input can overflow them
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<RangeInclusive<BigUint>>;
+    type Answer = BigUint;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_ranges(input, DECIMAL)
+    }
+
+    fn part1(ranges: &Self::Input) -> BigUint {
+        sum_over(ranges, DECIMAL, Repetitions::Twice).expect("big IDs don't overflow")
+    }
+
+    fn part2(ranges: &Self::Input) -> BigUint {
+        sum_over(ranges, DECIMAL, Repetitions::AtLeastTwice).expect("big IDs don't overflow")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigUint;
+
+    use super::{
+        DECIMAL, Day02, Overflow, Repeated,
+        Repetitions::{AtLeastTwice, Twice},
+        Solution, count_repeateds, merge_ranges, mobius, parse_ranges, repeateds, sum_over,
+        sum_repeateds, to_str_radix,
+    };
+
+    #[test]
+    fn test_mobius() {
+        let mu = (1..=12).map(mobius).collect::<Vec<_>>();
+        assert_eq!(mu, vec![1, -1, -1, 0, -1, 1, -1, 0, 0, 1, -1, 0]);
+    }
+
+    #[test]
+    fn test_repeateds() {
+        let is_repeated = |n: &u64| {
+            let digits = n.to_string();
+            (1..digits.len()).any(|len| digits == digits[..len].repeat(digits.len() / len))
+        };
+        let is_repeated_twice = |n: &u64| {
+            let digits = n.to_string();
+            let (first, second) = digits.split_at(digits.len() / 2);
+            first == second
+        };
+        for range in [1..=10_000, 95..=115, 998..=1012, 999_990..=1_011_111] {
+            let expected: u64 = range.clone().filter(is_repeated).sum();
+            assert_eq!(
+                sum_repeateds(&range, DECIMAL, AtLeastTwice),
+                Ok(expected),
+                "{range:?}"
+            );
+            let expected: u64 = range.clone().filter(is_repeated_twice).sum();
+            assert_eq!(
+                sum_repeateds(&range, DECIMAL, Twice),
+                Ok(expected),
+                "{range:?}"
+            );
+        }
+        // every number of up to 12 digits that repeats a shorter sequence, without listing them
+        assert_eq!(
+            sum_repeateds(&(1..=999_999_999_999_u64), DECIMAL, AtLeastTwice),
+            Ok(500_397_481_094_131_395)
+        );
+    }
+
+    #[test]
+    fn test_list_repeateds() {
+        let period = |n: &u64| {
+            let digits = n.to_string();
+            (1..digits.len()).find(|&len| digits == digits[..len].repeat(digits.len() / len))
+        };
+        for range in [1..=10_000, 95..=115, 998..=1012, 999_990..=1_011_111] {
+            let listed = repeateds(&range, DECIMAL, AtLeastTwice)
+                .unwrap()
+                .collect::<Vec<_>>();
+            let expected = range
+                .clone()
+                .filter_map(|id| {
+                    let period = period(&id)?;
+                    let repetitions = (id.to_string().len() / period) as u32;
+                    Some(Repeated {
+                        id,
+                        period: period as u32,
+                        repetitions,
+                    })
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(listed, expected, "{range:?}");
+            let count = count_repeateds(&range, DECIMAL, AtLeastTwice);
+            assert_eq!(count, Ok(expected.len() as u64), "{range:?}");
+
+            let listed = repeateds(&range, DECIMAL, Twice).unwrap().map(|r| r.id);
+            let sum = sum_repeateds(&range, DECIMAL, Twice).unwrap();
+            assert_eq!(listed.sum::<u64>(), sum, "{range:?}");
+        }
+        // 1111 repeats 1 four times, but 11 exactly twice
+        let twice = repeateds(&(1111..=1111_u64), DECIMAL, Twice).unwrap();
+        let expected = Repeated {
+            id: 1111,
+            period: 2,
+            repetitions: 2,
+        };
+        assert_eq!(twice.collect::<Vec<_>>(), vec![expected]);
+        assert_eq!(
+            count_repeateds(&(1..=999_999_999_999_u64), DECIMAL, AtLeastTwice),
+            Ok(1_010_007)
+        );
+    }
+
+    #[test]
+    fn test_merge_ranges() {
+        let ranges = parse_ranges::<u64>("11-22,20-30,31-40,99-95,0-5,0-0", DECIMAL).unwrap();
+        // the reversed range is empty, and zero has one digit
+        assert_eq!(sum_repeateds(&ranges[3], DECIMAL, AtLeastTwice), Ok(0));
+        assert_eq!(sum_repeateds(&ranges[4], DECIMAL, AtLeastTwice), Ok(0));
+        assert_eq!(
+            sum_over(&ranges, DECIMAL, AtLeastTwice),
+            Ok(11 + 22 * 2 + 33)
+        );
+        let merged = merge_ranges(&ranges);
+        assert_eq!(merged, vec![0..=5, 11..=40]);
+        assert_eq!(sum_over(&merged, DECIMAL, AtLeastTwice), Ok(11 + 22 + 33));
+        assert_eq!(merge_ranges(&[1..=u64::MAX, 5..=6]), vec![1..=u64::MAX]);
+        assert_eq!(
+            merge_ranges(&[u64::MAX..=u64::MAX, 0..=u64::MAX - 1]),
+            vec![0..=u64::MAX]
+        );
+        let big = ranges
+            .iter()
+            .map(|range| BigUint::from(*range.start())..=BigUint::from(*range.end()))
+            .collect::<Vec<_>>();
+        let expected = merged
+            .iter()
+            .map(|range| BigUint::from(*range.start())..=BigUint::from(*range.end()))
+            .collect::<Vec<_>>();
+        assert_eq!(merge_ranges(&big), expected);
+    }
+
+    #[test]
+    fn test_other_bases() {
+        let ranges = parse_ranges::<u64>("a-ff,3C-3E", 16).unwrap();
+        assert_eq!(ranges, vec![0xa..=0xff, 0x3c..=0x3e]);
+        // 0x11, 0x22, ..., 0xff
+        assert_eq!(
+            sum_repeateds(&ranges[0], 16, Twice),
+            Ok(0x11 * (1..16).sum::<u64>())
+        );
+        assert_eq!(sum_repeateds(&ranges[1], 16, AtLeastTwice), Ok(0));
+        // 0b11, 0b111, 0b1010, 0b1111
+        assert_eq!(
+            sum_repeateds(&(1..=0b1111_u64), 2, AtLeastTwice),
+            Ok(3 + 7 + 10 + 15)
+        );
+        // "zz" and "111" in base 36
+        assert_eq!(
+            sum_repeateds(&(1290..=1333_u64), 36, AtLeastTwice),
+            Ok(35 * 37 + 37 * 36 + 1)
+        );
+        assert_eq!(to_str_radix(&0x3c_u64, 16), "3c");
+        assert_eq!(to_str_radix(&BigUint::from(1290_u32), 36), "zu");
+        assert_eq!(to_str_radix(&0_u64, 2), "0");
+        let err = parse_ranges::<u64>("1-2,3-g", 16).unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (7, "a base 16 number"));
+    }
+
+    #[test]
+    fn test_overflow() {
+        // the puzzle's own parts can't overflow, whatever the input
+        let ranges = Day02::parse(&format!("1-{}", u128::MAX)).unwrap();
+        assert!(Day02::part2(&ranges) > BigUint::from(u128::MAX));
+
+        // the 20-digit IDs that fit in a u64 fit, but their sum doesn't
+        let range = 10_000_000_000_000_000_000..=u64::MAX;
+        assert_eq!(sum_repeateds(&range, DECIMAL, Twice), Err(Overflow("u64")));
+        let wide = u128::from(*range.start())..=u128::from(*range.end());
+        let sum = sum_repeateds(&wide, DECIMAL, Twice).unwrap();
+        assert_eq!(sum, 12_014_118_354_628_792_115_342_738_028);
+        let big = BigUint::from(*wide.start())..=BigUint::from(*wide.end());
+        assert_eq!(sum_repeateds(&big, DECIMAL, Twice), Ok(BigUint::from(sum)));
+        assert_eq!(
+            sum_repeateds(&(1..=u128::MAX), DECIMAL, AtLeastTwice),
+            Err(Overflow("u128"))
+        );
+
+        // far past u128
+        let big = "1".repeat(30).parse::<BigUint>().unwrap()..="9".repeat(40).parse().unwrap();
+        assert_eq!(
+            sum_repeateds(&big, DECIMAL, AtLeastTwice)
+                .unwrap()
+                .to_string(),
+            "495495500446485494332098744887014337464777469383097629713420"
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use num_bigint::BigUint;
+
+use crate::{
+    parse::{ParseError, lines},
+    solution::Solution,
+};
+
+/// number of batteries part 2 turns on in each bank
+pub const SEQ_LEN: usize = 12;
+
+/// the batteries turned on in a bank, and the joltage they make
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Joltage {
+    /// exact for any number of batteries
+    pub value: BigUint,
+    /// indices into the bank, in ascending order
+    pub positions: Vec<usize>,
+}
+
+/// largest `len`-digit number that can be made from the bank, keeping digits in order,
+/// or `None` if the bank has fewer than `len` batteries
+pub fn joltage(bank: &[u8], len: usize) -> Option<Joltage> {
+    if len > bank.len() {
+        return None;
+    }
+    // a monotonic stack: a digit evicts smaller ones before it, as long as enough digits
+    // are left to refill the sequence
+    let mut positions: Vec<usize> = Vec::with_capacity(len);
+    for (i, &b) in bank.iter().enumerate() {
+        let remaining = bank.len() - i;
+        while positions
+            .last()
+            .is_some_and(|&top| bank[top] < b && positions.len() + remaining > len)
+        {
+            positions.pop();
+        }
+        if positions.len() < len {
+            positions.push(i);
+        }
+    }
+    let digits = positions.iter().map(|&i| bank[i]).collect::<Vec<_>>();
+    let value = BigUint::from_radix_be(&digits, 10).expect("batteries are digits");
+    Some(Joltage { value, positions })
+}
+
+/// parses one bank of batteries per line, each with at least `min_len` of them,
+/// skipping blank lines
+pub fn parse_banks(input: &str, min_len: usize) -> Result<Vec<Vec<u8>>, ParseError> {
+    lines(input)
+        .filter(|line| !line.text.trim().is_empty())
+        .map(|line| {
+            let bank = line
+                .text
+                .char_indices()
+                .map(|(i, c)| match c.to_digit(10) {
+                    Some(digit) => Ok(digit as u8),
+                    None => Err(line.error(&line.text[i..i + c.len_utf8()], "a digit")),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if bank.len() < min_len {
+                return Err(line.error(line.text, format!("at least {min_len} batteries")));
+            }
+            Ok(bank)
+        })
+        .collect()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    /// each bank's batteries, as digits
+    type Input = Vec<Vec<u8>>;
+    type Answer = BigUint;
+
+    /// only checks that each bank is long enough for part 1; see [`solve_part2`]
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_banks(input, 2)
+    }
+
+    fn part1(banks: &Self::Input) -> BigUint {
+        banks
+            .iter()
+            .map(|bank| joltage(bank, 2).expect("bank is long enough").value)
+            .sum()
+    }
+
+    /// panics if a bank has fewer than [`SEQ_LEN`] batteries
+    fn part2(banks: &Self::Input) -> BigUint {
+        banks
+            .iter()
+            .map(|bank| joltage(bank, SEQ_LEN).expect("bank is long enough").value)
+            .sum()
+    }
+}
+
+/// part 2's [`Solver`](super::Solver), which parses each bank as needing [`SEQ_LEN`]
+/// batteries, rather than the 2 that part 1 needs
+pub fn solve_part2(input: &str) -> Result<String, ParseError> {
+    Ok(Day03::part2(&parse_banks(input, SEQ_LEN)?).to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigUint;
+
+    use super::{Day03, Joltage, Solution, joltage, solve_part2};
+
+    /// the largest `len`-digit subsequence, trying every one
+    fn brute_force(bank: &[u8], len: usize) -> u64 {
+        (0..1_u32 << bank.len())
+            .filter(|chosen| chosen.count_ones() as usize == len)
+            .map(|chosen| {
+                (0..bank.len())
+                    .filter(|i| chosen >> i & 1 == 1)
+                    .fold(0, |acc, i| acc * 10 + u64::from(bank[i]))
+            })
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_joltage() {
+        let bank = [8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1];
+        let expected = Joltage {
+            value: BigUint::from(92_u32),
+            positions: vec![6, 11],
+        };
+        assert_eq!(joltage(&bank, 2), Some(expected));
+        assert_eq!(joltage(&bank, 16), None);
+        let chosen = joltage(&bank, 12).unwrap();
+        assert_eq!(chosen.value, BigUint::from(888_911_112_111_u64));
+        assert_eq!(
+            chosen.positions,
+            vec![0, 2, 4, 6, 7, 8, 9, 10, 11, 12, 13, 14]
+        );
+        for bank in [
+            &bank[..],
+            &[9, 8, 7, 6, 5],
+            &[1, 2, 3, 4, 5],
+            &[3, 3, 1, 3, 3, 1],
+        ] {
+            for len in 1..=bank.len() {
+                let chosen = joltage(bank, len).unwrap();
+                let expected = BigUint::from(brute_force(bank, len));
+                assert_eq!(chosen.value, expected, "{bank:?} {len}");
+                assert!(chosen.positions.is_sorted(), "{bank:?} {len}");
+                let value =
+                    (chosen.positions.iter()).fold(0, |acc, &i| acc * 10 + u64::from(bank[i]));
+                assert_eq!(BigUint::from(value), chosen.value, "{bank:?} {len}");
+            }
+        }
+        // far past u64
+        let bank = concat!(
+            "88351709269740539376685174152645421743168853765324304328277593140987966086426514",
+            "32360911610594534981214725636418343080096267301965520025009455554167379739563137",
+            "9738616668863717281324853916583549311656",
+        );
+        let bank = bank.bytes().map(|b| b - b'0').collect::<Vec<_>>();
+        assert_eq!(
+            joltage(&bank, 50).unwrap().value.to_string(),
+            "99999999999999986668863717281324853916583549311656"
+        );
+        assert_eq!(
+            joltage(&bank, 100).unwrap().value.to_string(),
+            concat!(
+                "99999999987834308009626730196552002500945555416737973956313797386166688637172813",
+                "24853916583549311656",
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let banks = Day03::parse("987654321111111\n\n").unwrap();
+        assert_eq!(
+            banks,
+            vec![vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1]]
+        );
+        let banks = Day03::parse("987654321111111\n12345\n").unwrap();
+        assert_eq!(Day03::part1(&banks), BigUint::from(98_u32 + 45));
+        let err = Day03::parse("987654321111111\n1\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.expected.as_str()),
+            (2, "at least 2 batteries")
+        );
+        let err = solve_part2("987654321111111\n12345\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.expected.as_str()),
+            (2, "at least 12 batteries")
+        );
+        let err = Day03::parse("98765x321111111").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (6, "x"));
+    }
+}
@@ -0,0 +1,56 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    parse::{ParseError, lines},
+    position::Position,
+    solution::Solution,
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    /// positions of the paper rolls
+    type Input = BTreeSet<Position>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut rolls = BTreeSet::new();
+        for (row, line) in lines(input).enumerate() {
+            for (col, c) in line.text.char_indices() {
+                match c {
+                    '@' => {
+                        rolls.insert(Position { row, col });
+                    }
+                    '.' => {}
+                    _ => return Err(line.error(&line.text[col..col + c.len_utf8()], "@ or .")),
+                }
+            }
+        }
+        Ok(rolls)
+    }
+
+    fn part1(rolls: &Self::Input) -> usize {
+        rolls
+            .iter()
+            .filter(|pos| pos.adjacent().filter(|adj| rolls.contains(adj)).count() < 4)
+            .count()
+    }
+
+    fn part2(rolls: &Self::Input) -> usize {
+        let mut rolls = rolls.clone();
+        let mut removed = 0;
+        loop {
+            let to_remove: BTreeSet<Position> = rolls
+                .iter()
+                .copied()
+                .filter(|pos| pos.adjacent().filter(|adj| rolls.contains(adj)).count() < 4)
+                .collect();
+            if to_remove.is_empty() {
+                break;
+            }
+            rolls = &rolls - &to_remove;
+            removed += to_remove.len()
+        }
+        removed
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{
+    parse::{ParseError, lines},
+    range_set::RangeSet,
+    solution::Solution,
+};
+
+pub struct Inventory {
+    fresh: RangeSet<usize>,
+    available: Vec<usize>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Inventory;
+    type Answer = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = lines(input);
+        let mut fresh = RangeSet::new();
+        for line in lines.by_ref().take_while(|line| !line.text.is_empty()) {
+            fresh
+                .try_add(line.parse_range(line.text)?)
+                .map_err(|_| line.error(line.text, "a range that ends before the largest ID"))?;
+        }
+        let available = lines
+            .map(|line| line.parse(line.text, "an ingredient ID"))
+            .collect::<Result<_, _>>()?;
+        Ok(Inventory { fresh, available })
+    }
+
+    fn part1(inventory: &Self::Input) -> u128 {
+        inventory.fresh.contains_all(&inventory.available).len() as u128
+    }
+
+    fn part2(inventory: &Self::Input) -> u128 {
+        inventory.fresh.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Day05, Solution};
+
+    #[test]
+    fn test_parse_largest_id() {
+        let max = usize::MAX;
+        let err = Day05::parse(&format!("3-5\n0-{max}\n\n1\n")).err().unwrap();
+        assert_eq!(
+            (err.line, err.expected.as_str()),
+            (2, "a range that ends before the largest ID")
+        );
+        let inventory = Day05::parse(&format!("0-{}\n\n1\n", max - 1)).unwrap();
+        assert_eq!(Day05::part2(&inventory), max as u128);
+    }
+}
@@ -0,0 +1,150 @@
+use itertools::Itertools;
+
+use crate::{
+    cephalop::Cephalop,
+    parse::{ParseError, lines},
+    solution::Solution,
+};
+
+/// one problem on the worksheet: its operation, and the block of characters
+/// above it, one string per row, spanning the problem's columns.
+/// Each row is blank or a number padded with spaces, and so is each column,
+/// both checked when parsing.
+pub struct Problem {
+    cephalop: Cephalop,
+    rows: Vec<String>,
+}
+
+impl Problem {
+    /// operands are read left to right along each row
+    fn row_operands(&self) -> impl Iterator<Item = usize> {
+        self.rows
+            .iter()
+            .map(|row| row.trim())
+            .filter(|operand_str| !operand_str.is_empty())
+            .map(|operand_str| operand_str.parse::<usize>().expect("checked when parsing"))
+    }
+
+    /// the characters down each column, top to bottom
+    fn columns(&self) -> impl Iterator<Item = String> {
+        let width = self.rows.iter().map(String::len).max().unwrap_or_default();
+        (0..width).map(|i| {
+            self.rows
+                .iter()
+                .filter_map(|row| row.as_bytes().get(i).copied().map(char::from))
+                .collect()
+        })
+    }
+
+    /// operands are read top to bottom down each column
+    fn column_operands(&self) -> impl Iterator<Item = usize> {
+        self.columns().filter_map(|column| {
+            let operand_str = column.trim();
+            if operand_str.is_empty() {
+                None
+            } else {
+                Some(operand_str.parse::<usize>().expect("checked when parsing"))
+            }
+        })
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Problem>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines = lines(input).collect_vec();
+        let Some((cephalops, rows)) = lines.split_last() else {
+            return Err(ParseError::end_of_input(
+                input,
+                "a row of + and * operators",
+            ));
+        };
+        cephalops
+            .text
+            .match_indices(['+', '*'])
+            .map(|(i, _)| i)
+            .chain([usize::MAX])
+            .tuple_windows()
+            .map(|(lo, hi)| {
+                let cephalop_str = cephalops.text[lo..hi.min(cephalops.text.len())].trim();
+                let cephalop = cephalops.parse::<Cephalop>(cephalop_str, "+ or *")?;
+                let cells = rows
+                    .iter()
+                    .map(|row| {
+                        let cell = row.text.get(lo..hi.min(row.text.len())).unwrap_or_default();
+                        let operand_str = cell.trim();
+                        if !operand_str.is_empty() {
+                            row.parse::<usize>(operand_str, "a number")?;
+                        }
+                        Ok(cell.to_owned())
+                    })
+                    .collect::<Result<_, _>>()?;
+                let problem = Problem {
+                    cephalop,
+                    rows: cells,
+                };
+                for (i, column) in problem.columns().enumerate() {
+                    let operand_str = column.trim();
+                    if operand_str.is_empty() || operand_str.parse::<usize>().is_ok() {
+                        continue;
+                    }
+                    // the lowest digit in the column is the one after the gap, or the one
+                    // that made the number too big
+                    let (row, _) = rows
+                        .iter()
+                        .zip(&problem.rows)
+                        .rev()
+                        .find(|(_, cell)| {
+                            cell.as_bytes()
+                                .get(i)
+                                .is_some_and(|b| !b.is_ascii_whitespace())
+                        })
+                        .expect("the column isn't blank");
+                    let found = row.text.get(lo + i..=lo + i).unwrap_or(row.text);
+                    return Err(row.error(found, "a column of digits without gaps"));
+                }
+                Ok(problem)
+            })
+            .collect()
+    }
+
+    fn part1(problems: &Self::Input) -> usize {
+        problems
+            .iter()
+            .map(|problem| problem.cephalop.reduce(problem.row_operands()))
+            .sum()
+    }
+
+    fn part2(problems: &Self::Input) -> usize {
+        problems
+            .iter()
+            .map(|problem| problem.cephalop.reduce(problem.column_operands()))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Day06, Solution};
+
+    #[test]
+    fn test_parse_columns() {
+        let err = Day06::parse("1  \n 2 \n3  \n+  \n").err().unwrap();
+        assert_eq!(
+            (
+                err.line,
+                err.column,
+                err.found.as_str(),
+                err.expected.as_str()
+            ),
+            (3, 1, "3", "a column of digits without gaps")
+        );
+        let problems = Day06::parse("12 \n 34\n+  \n").unwrap();
+        assert_eq!(Day06::part1(&problems), 12 + 34);
+        assert_eq!(Day06::part2(&problems), 1 + 23 + 4);
+    }
+}
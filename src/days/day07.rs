@@ -0,0 +1,123 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    parse::{ParseError, lines},
+    solution::Solution,
+};
+
+struct Splits {
+    beams: HashSet<usize>,
+    splits: usize,
+}
+
+impl Splits {
+    fn new(start: usize) -> Self {
+        Self {
+            beams: [start].into(),
+            splits: 0,
+        }
+    }
+
+    fn step(self, splitters: &HashSet<usize>) -> Self {
+        let Self { beams, mut splits } = self;
+        let mut new_beams = HashSet::new();
+        for beam in beams {
+            if splitters.contains(&beam) {
+                new_beams.insert(beam - 1);
+                new_beams.insert(beam + 1);
+                splits += 1;
+            } else {
+                new_beams.insert(beam);
+            }
+        }
+        Self {
+            beams: new_beams,
+            splits,
+        }
+    }
+}
+
+struct Timelines {
+    timelines: HashMap<usize, usize>,
+}
+
+impl Timelines {
+    fn new(start: usize) -> Self {
+        Self {
+            timelines: [(start, 1)].into(),
+        }
+    }
+
+    fn step(self, splitters: &HashSet<usize>) -> Self {
+        let Self { timelines } = self;
+        let mut new_timelines = HashMap::new();
+        for (beam, count) in timelines {
+            if splitters.contains(&beam) {
+                *new_timelines.entry(beam - 1).or_default() += count;
+                *new_timelines.entry(beam + 1).or_default() += count;
+            } else {
+                *new_timelines.entry(beam).or_default() += count;
+            }
+        }
+        Self {
+            timelines: new_timelines,
+        }
+    }
+
+    fn timelines(&self) -> usize {
+        self.timelines.values().sum()
+    }
+}
+
+pub struct Manifold {
+    start: usize,
+    /// splitter positions of each row after the first
+    rows: Vec<HashSet<usize>>,
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Manifold;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = lines(input);
+        let first = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(input, "a row with a starting position S"))?;
+        let start = first
+            .text
+            .find('S')
+            .ok_or_else(|| first.error(first.text, "a starting position S"))?;
+        let rows = lines
+            .map(|line| {
+                line.text
+                    .char_indices()
+                    .filter_map(|(i, c)| match c {
+                        '^' => Some(Ok(i)),
+                        '.' => None,
+                        _ => Some(Err(line.error(&line.text[i..i + c.len_utf8()], ". or ^"))),
+                    })
+                    .collect::<Result<HashSet<usize>, _>>()
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Manifold { start, rows })
+    }
+
+    fn part1(manifold: &Self::Input) -> usize {
+        let state = manifold
+            .rows
+            .iter()
+            .fold(Splits::new(manifold.start), Splits::step);
+        state.splits
+    }
+
+    fn part2(manifold: &Self::Input) -> usize {
+        let state = manifold
+            .rows
+            .iter()
+            .fold(Timelines::new(manifold.start), Timelines::step);
+        state.timelines()
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::BinaryHeap;
+
+use itertools::Itertools;
+
+use crate::{
+    distanced::Distanced,
+    parse::{ParseError, lines},
+    point::Point,
+    solution::Solution,
+    union_find::UnionFind,
+};
+
+const TOP_CIRCUITS: usize = 3;
+
+/// every pair of points, closest first
+fn distances(points: &[Point]) -> BinaryHeap<Distanced<(usize, usize)>> {
+    (0..points.len())
+        .cartesian_product(0..points.len())
+        .filter(|(a, b)| a < b)
+        .map(|(a, b)| Distanced {
+            distance: points[a].distance(&points[b]),
+            element: (a, b),
+        })
+        .collect()
+}
+
+/// `CONNECTIONS` is how many of the closest pairs part 1 connects;
+/// the puzzle's example makes only 10
+pub struct Day08<const CONNECTIONS: usize = 1000>;
+
+impl<const CONNECTIONS: usize> Solution for Day08<CONNECTIONS> {
+    type Input = Vec<Point>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        lines(input)
+            .map(|line| line.parse_with::<Point>(line.text))
+            .collect()
+    }
+
+    fn part1(points: &Self::Input) -> usize {
+        let mut distances = distances(points);
+        let mut union_find = UnionFind::new(points.len());
+        for _ in 0..CONNECTIONS {
+            let Distanced {
+                distance: _,
+                element: (a, b),
+            } = distances.pop().expect("more pairs than connections");
+            union_find.unify(a, b)
+        }
+        let mut classes: BinaryHeap<usize> = union_find.classes().into_iter().collect();
+        (0..TOP_CIRCUITS)
+            .map(|_| classes.pop().expect("at least {TOP_CIRCUITS} classes"))
+            .product()
+    }
+
+    fn part2(points: &Self::Input) -> usize {
+        let mut distances = distances(points);
+        let mut union_find = UnionFind::new(points.len());
+        loop {
+            let Distanced {
+                distance: _,
+                element: (a, b),
+            } = distances.pop().expect("more pairs than connections");
+            union_find.unify(a, b);
+            if union_find.n_classes() == 1 {
+                break points[a].x * points[b].x;
+            }
+        }
+    }
+}
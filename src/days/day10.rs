@@ -0,0 +1,316 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    str::FromStr,
+};
+
+use bitvec::array::BitArray;
+use itertools::Itertools;
+#[cfg(feature = "z3")]
+use z3::{Optimize, SatResult, ast::Int};
+
+use crate::{
+    parse::{ParseError, lines},
+    solution::Solution,
+};
+
+type CounterIndex = usize;
+type CounterValue = u64;
+
+/// most buttons a machine can have, so that [`Machine::solve_joltage_native`] can number
+/// every subset of them with a `u64`
+pub const MAX_BUTTONS: usize = 63;
+
+pub struct Machine {
+    goal: BitArray,
+    buttons: Vec<HashSet<CounterIndex>>,
+    joltage: Vec<CounterValue>,
+}
+
+/// parses `field`, a slice of `s`, as a comma-separated list wrapped in `delimiters`
+fn parse_list<T: FromStr>(
+    s: &str,
+    field: &str,
+    delimiters: (char, char),
+    expected: &str,
+) -> Result<Vec<T>, ParseError> {
+    let (open, close) = delimiters;
+    let items = field
+        .strip_prefix(open)
+        .and_then(|field| field.strip_suffix(close))
+        .ok_or_else(|| ParseError::within(s, field, format!("a list like {open}0,1{close}")))?;
+    items
+        .split(',')
+        .map(|item| {
+            item.parse()
+                .map_err(|_| ParseError::within(s, item, expected))
+        })
+        .collect()
+}
+
+impl FromStr for Machine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split(' ');
+        let goal_field = fields.next().unwrap_or_default();
+        let goal_str = goal_field
+            .strip_prefix('[')
+            .and_then(|goal_str| goal_str.strip_suffix(']'))
+            .ok_or_else(|| ParseError::within(s, goal_field, "indicator lights like [.##.]"))?;
+        let mut goal = BitArray::new([0]);
+        if goal_str.len() > goal.len() {
+            let expected = format!("at most {} indicator lights", goal.len());
+            return Err(ParseError::within(s, goal_field, expected));
+        }
+        for (i, c) in goal_str.char_indices() {
+            match c {
+                '#' => goal.set(i, true),
+                '.' => {}
+                _ => {
+                    let found = &goal_str[i..i + c.len_utf8()];
+                    return Err(ParseError::within(s, found, ". or #"));
+                }
+            }
+        }
+
+        let joltage_field = fields
+            .next_back()
+            .ok_or_else(|| ParseError::within(s, &s[s.len()..], "button wiring schematics"))?;
+        let joltage: Vec<CounterValue> = parse_list(s, joltage_field, ('{', '}'), "a joltage")?;
+        if joltage.len() != goal_str.len() {
+            let expected = format!("{} joltages, one per indicator light", goal_str.len());
+            return Err(ParseError::within(s, joltage_field, expected));
+        }
+
+        let expected_index = format!("a counter index below {}", goal_str.len());
+        let buttons = fields
+            .enumerate()
+            .map(|(b, schematic)| {
+                if b == MAX_BUTTONS {
+                    let expected = format!("at most {MAX_BUTTONS} buttons");
+                    return Err(ParseError::within(s, schematic, expected));
+                }
+                let wirings: Vec<CounterIndex> =
+                    parse_list(s, schematic, ('(', ')'), &expected_index)?;
+                if wirings.iter().any(|&i| i >= goal_str.len()) {
+                    Err(ParseError::within(s, schematic, &expected_index))
+                } else {
+                    Ok(wirings.into_iter().collect())
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if buttons.is_empty() {
+            return Err(ParseError::within(
+                s,
+                joltage_field,
+                "button wiring schematics",
+            ));
+        }
+
+        Ok(Self {
+            goal,
+            buttons,
+            joltage,
+        })
+    }
+}
+
+impl Machine {
+    /// returns the shortest sequence of button presses to reach this machine's indicator light goal
+    fn solve_lights(&self) -> u64 {
+        let start: BitArray = BitArray::new([0]);
+        debug_assert_ne!(self.goal, start);
+        let edges = self
+            .buttons
+            .iter()
+            .map(|counters| {
+                let mut button = BitArray::new([0]);
+                for &i in counters {
+                    button.set(i, true);
+                }
+                button
+            })
+            .collect_vec();
+        let mut seen = HashSet::from([start]);
+        let mut queue = VecDeque::from([(start, 0)]);
+        loop {
+            let (node, presses) = queue.pop_front().unwrap();
+            let presses = presses + 1;
+            for edge in &edges {
+                let neighbor = node ^ edge;
+                if seen.insert(neighbor) {
+                    if neighbor == self.goal {
+                        return presses;
+                    } else {
+                        queue.push_back((neighbor, presses))
+                    }
+                }
+            }
+        }
+    }
+
+    /// returns the shortest sequence of button presses to reach this machine's joltage goal,
+    /// using z3 if it's enabled
+    fn solve_joltage(&self) -> u64 {
+        #[cfg(feature = "z3")]
+        return self.solve_joltage_z3();
+        #[cfg(not(feature = "z3"))]
+        return self.solve_joltage_native();
+    }
+
+    /// like [`Self::solve_joltage`], but always with the built-in solver.
+    ///
+    /// Any way to reach the joltages presses each button an odd or even number of times,
+    /// so it is some subset of buttons pressed once, plus twice a way to reach half of
+    /// what remains. The subset is constrained to flip exactly the counters whose
+    /// joltage is odd, so recurse on each such subset, halving the joltages each time.
+    ///
+    /// Exponential in the number of buttons: it lists all 2^n subsets of them up front,
+    /// which is why parsing allows at most [`MAX_BUTTONS`].
+    pub fn solve_joltage_native(&self) -> u64 {
+        // every subset of buttons, by which counters it leaves odd: (joltage added, presses)
+        let mut subsets: HashMap<u64, Vec<(Vec<CounterValue>, u64)>> = HashMap::new();
+        for subset in 0..1_u64 << self.buttons.len() {
+            let mut added = vec![0; self.joltage.len()];
+            for (_, counters) in self
+                .buttons
+                .iter()
+                .enumerate()
+                .filter(|(b, _)| subset >> b & 1 == 1)
+            {
+                for &i in counters {
+                    added[i] += 1;
+                }
+            }
+            subsets
+                .entry(odd_counters(&added))
+                .or_default()
+                .push((added, subset.count_ones().into()));
+        }
+        min_presses(&self.joltage, &subsets, &mut HashMap::new())
+            .expect("joltage goal is reachable")
+    }
+
+    /// like [`Self::solve_joltage`], but always with z3
+    #[cfg(feature = "z3")]
+    pub fn solve_joltage_z3(&self) -> u64 {
+        let optimizer = Optimize::new();
+        let vars = self
+            .buttons
+            .iter()
+            .map(|counters| {
+                let var = Int::fresh_const("b");
+                optimizer.assert(&var.ge(0));
+                (var, counters)
+            })
+            .collect_vec();
+        for (i, &target) in self.joltage.iter().enumerate() {
+            let sum: Int = vars
+                .iter()
+                .filter(|(_var, counters)| counters.contains(&i))
+                .map(|(var, _counters)| var)
+                .sum();
+            optimizer.assert(&sum.eq(target))
+        }
+        let to_minimize: Int = vars.iter().map(|(var, _counters)| var).sum();
+        optimizer.minimize(&to_minimize);
+        let sat = optimizer.check(&[]);
+        assert_eq!(sat, SatResult::Sat);
+        let model = optimizer.get_model().unwrap();
+        model.eval(&to_minimize, false).unwrap().as_u64().unwrap()
+    }
+}
+
+/// bitmask of which counters have odd joltage
+fn odd_counters(joltage: &[CounterValue]) -> u64 {
+    joltage
+        .iter()
+        .enumerate()
+        .filter(|(_, j)| *j % 2 == 1)
+        .fold(0, |mask, (i, _)| mask | 1 << i)
+}
+
+/// fewest presses to reach `joltage`, or `None` if it can't be reached
+fn min_presses(
+    joltage: &[CounterValue],
+    subsets: &HashMap<u64, Vec<(Vec<CounterValue>, u64)>>,
+    memo: &mut HashMap<Vec<CounterValue>, Option<u64>>,
+) -> Option<u64> {
+    if joltage.iter().all(|&j| j == 0) {
+        return Some(0);
+    }
+    if let Some(&presses) = memo.get(joltage) {
+        return presses;
+    }
+    let presses = subsets
+        .get(&odd_counters(joltage))
+        .into_iter()
+        .flatten()
+        .filter(|(added, _)| added.iter().zip(joltage).all(|(a, j)| a <= j))
+        .filter_map(|(added, presses)| {
+            let half = joltage
+                .iter()
+                .zip(added)
+                .map(|(j, a)| (j - a) / 2)
+                .collect_vec();
+            Some(presses + 2 * min_presses(&half, subsets, memo)?)
+        })
+        .min();
+    memo.insert(joltage.to_vec(), presses);
+    presses
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Machine>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        lines(input)
+            .map(|line| line.parse_with::<Machine>(line.text))
+            .collect()
+    }
+
+    fn part1(machines: &Self::Input) -> u64 {
+        machines.iter().map(Machine::solve_lights).sum()
+    }
+
+    fn part2(machines: &Self::Input) -> u64 {
+        machines.iter().map(Machine::solve_joltage).sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Day10, MAX_BUTTONS, Machine, Solution};
+
+    const EXAMPLE: &str = include_str!("../../fixtures/10.txt");
+
+    #[test]
+    fn test_solve_joltage_native() {
+        let machines = Day10::parse(EXAMPLE).unwrap();
+        let presses: Vec<u64> = machines.iter().map(|m| m.solve_joltage_native()).collect();
+        assert_eq!(presses, vec![10, 12, 11]);
+    }
+
+    #[test]
+    fn test_parse_max_buttons() {
+        let machine = |buttons: usize| format!("[#] {} {{1}}", vec!["(0)"; buttons].join(" "));
+        assert!(machine(MAX_BUTTONS).parse::<Machine>().is_ok());
+        let too_many = machine(MAX_BUTTONS + 1);
+        let err = too_many.parse::<Machine>().err().unwrap();
+        assert_eq!(
+            (err.column, err.expected.as_str()),
+            (5 + 4 * MAX_BUTTONS, "at most 63 buttons")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "z3")]
+    fn test_solve_joltage_backends_agree() {
+        for machine in Day10::parse(EXAMPLE).unwrap() {
+            assert_eq!(machine.solve_joltage_native(), machine.solve_joltage_z3());
+        }
+    }
+}
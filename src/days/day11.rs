@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use crate::{
+    parse::{ParseError, lines},
+    solution::Solution,
+};
+
+/// stores an INVERSION of the adjacency graph in the input:
+/// output maps to its inputs
+pub struct Graph {
+    output_to_inputs: OutputToInputs,
+}
+type OutputToInputs = HashMap<String, Vec<String>>;
+/// number of paths that visited neither, only dac, only fft, or both
+type Paths = [usize; 4];
+
+const DAC: usize = 0b01;
+const FFT: usize = 0b10;
+
+impl Graph {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut output_to_inputs: OutputToInputs = HashMap::new();
+        for line in lines(input) {
+            let (input, outputs_str) = line
+                .text
+                .split_once(": ")
+                .ok_or_else(|| line.error(line.text, "a device, then `: ` and its outputs"))?;
+            for output in outputs_str.split(' ') {
+                output_to_inputs
+                    .entry(output.to_owned())
+                    .or_default()
+                    .push(input.to_owned());
+            }
+        }
+        Ok(Self { output_to_inputs })
+    }
+
+    fn inputs(&self, node: &str) -> &[String] {
+        self.output_to_inputs
+            .get(node)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// `paths_to` memoizes the number of paths from the source to each node,
+    /// and must be seeded with the source itself
+    fn paths(&self, node: &str, paths_to: &mut HashMap<String, usize>) -> usize {
+        if let Some(&n) = paths_to.get(node) {
+            return n;
+        }
+
+        let res = self
+            .inputs(node)
+            .iter()
+            .map(|input| self.paths(input, paths_to))
+            .sum();
+        paths_to.insert(node.to_owned(), res);
+        res
+    }
+
+    /// like [`Self::paths`], but keeps track of which of dac and fft each path visited
+    fn paths_via(&self, node: &str, paths_to: &mut HashMap<String, Paths>) -> Paths {
+        if let Some(&n) = paths_to.get(node) {
+            return n;
+        }
+
+        let mut paths = [0; 4];
+        for input in self.inputs(node) {
+            let input_paths = self.paths_via(input, paths_to);
+            let mut mask = 0;
+            if node == "dac" {
+                mask |= DAC;
+            }
+            if node == "fft" {
+                mask |= FFT;
+            }
+            for i in 0..4 {
+                paths[i | mask] += input_paths[i]
+            }
+        }
+        paths_to.insert(node.to_owned(), paths);
+        paths
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Graph;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Graph::parse(input)
+    }
+
+    fn part1(graph: &Self::Input) -> usize {
+        let mut paths_to = HashMap::from([("you".to_owned(), 1)]);
+        graph.paths("out", &mut paths_to)
+    }
+
+    fn part2(graph: &Self::Input) -> usize {
+        let mut paths_to = HashMap::from([("svr".to_owned(), [1, 0, 0, 0])]);
+        graph.paths_via("out", &mut paths_to)[DAC | FFT]
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{parse::ParseError, solution::Solution};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+
+/// common entry point for one part of one day: puzzle input in, printable answer out
+pub type Solver = fn(&str) -> Result<String, ParseError>;
+
+pub struct Day {
+    pub number: u8,
+    /// part 1, then part 2
+    pub parts: [Solver; 2],
+}
+
+impl Day {
+    const fn new<S: Solution>(number: u8) -> Self {
+        Self {
+            number,
+            parts: [solve_part1::<S>, solve_part2::<S>],
+        }
+    }
+}
+
+fn solve_part1<S: Solution>(input: &str) -> Result<String, ParseError> {
+    Ok(S::part1(&S::parse(input)?).to_string())
+}
+
+fn solve_part2<S: Solution>(input: &str) -> Result<String, ParseError> {
+    Ok(S::part2(&S::parse(input)?).to_string())
+}
+
+pub const DAYS: [Day; 11] = [
+    Day::new::<day01::Day01>(1),
+    Day::new::<day02::Day02>(2),
+    // part 2 needs longer banks than parsing checks for
+    Day {
+        number: 3,
+        parts: [solve_part1::<day03::Day03>, day03::solve_part2],
+    },
+    Day::new::<day04::Day04>(4),
+    Day::new::<day05::Day05>(5),
+    Day::new::<day06::Day06>(6),
+    Day::new::<day07::Day07>(7),
+    Day::new::<day08::Day08>(8),
+    Day::new::<day09::Day09>(9),
+    Day::new::<day10::Day10>(10),
+    Day::new::<day11::Day11>(11),
+];
+
+pub fn day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,636 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    ops::{AddAssign, RangeInclusive},
+    str::FromStr,
+};
+
+pub const DIAL_SIZE: isize = 100;
+pub const DIAL_START: isize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub struct DirectionParseError(String);
+
+/// `L`, `R`, `Left` or `Right`, in any case
+impl FromStr for Direction {
+    type Err = DirectionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "l" | "left" => Ok(Self::Left),
+            "r" | "right" => Ok(Self::Right),
+            _ => Err(DirectionParseError(format!(
+                "Expected L, R, Left or Right, but received {s}"
+            ))),
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::Left => write!(f, "L"),
+            Direction::Right => write!(f, "R"),
+        }
+    }
+}
+
+pub type Distance = isize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    pub direction: Direction,
+    pub distance: Distance,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RotationParseError {
+    #[error("Expected a rotation, but received nothing")]
+    Empty,
+    #[error("Error parsing direction: {0}")]
+    Direction(#[from] DirectionParseError),
+    #[error("Expected a distance after the direction")]
+    MissingDistance,
+    #[error("Expected a distance without a sign after the direction")]
+    SignedDistance,
+    #[error("Error parsing distance: {0}")]
+    Distance(#[from] std::num::ParseIntError),
+    #[error("Expected a distance of at most {}", Distance::MAX)]
+    DistanceOverflow,
+}
+
+impl RotationParseError {
+    /// what should have been where parsing failed
+    pub fn expected(&self) -> &'static str {
+        match self {
+            Self::Empty => "a rotation like L68",
+            Self::Direction(_) => "L, R, Left or Right",
+            Self::MissingDistance | Self::Distance(_) => "a distance",
+            Self::SignedDistance => "a distance without a sign",
+            Self::DistanceOverflow => "a shorter distance",
+        }
+    }
+}
+
+/// a direction and then a distance, optionally separated by whitespace, like `L68`
+/// or `right 5`; or a signed distance alone, negative to the left, like `-68`
+impl FromStr for Rotation {
+    type Err = RotationParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_spanned(s).map_err(|(err, _)| err)
+    }
+}
+
+impl Rotation {
+    /// like `s.parse()`, but on failure also returns the slice of `s` that was wrong
+    pub fn parse_spanned(s: &str) -> Result<Self, (RotationParseError, &str)> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err((RotationParseError::Empty, s));
+        }
+        let (direction_str, distance_str) =
+            s.split_at(s.find(|c: char| !c.is_alphabetic()).unwrap_or(s.len()));
+        if direction_str.is_empty() {
+            let distance: Distance = distance_str
+                .parse()
+                .map_err(|err| (RotationParseError::Distance(err), distance_str))?;
+            let direction = if distance < 0 {
+                Direction::Left
+            } else {
+                Direction::Right
+            };
+            let distance = distance
+                .checked_abs()
+                .ok_or((RotationParseError::DistanceOverflow, distance_str))?;
+            return Ok(Self {
+                direction,
+                distance,
+            });
+        }
+        let direction = direction_str
+            .parse()
+            .map_err(|err| (RotationParseError::Direction(err), direction_str))?;
+        let distance_str = distance_str.trim_start();
+        if distance_str.is_empty() {
+            return Err((RotationParseError::MissingDistance, distance_str));
+        }
+        if distance_str.starts_with(['+', '-']) {
+            return Err((RotationParseError::SignedDistance, &distance_str[..1]));
+        }
+        let distance = distance_str
+            .parse()
+            .map_err(|err| (RotationParseError::Distance(err), distance_str))?;
+        Ok(Self {
+            direction,
+            distance,
+        })
+    }
+}
+
+impl Display for Rotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.direction, self.distance)
+    }
+}
+
+impl Rotation {
+    /// does NOT modulo the dial size; callers that want the dial position
+    /// should `rem_euclid` the result by their dial size. `None` if it overflows.
+    pub fn apply(&self, dial: isize) -> Option<isize> {
+        match self.direction {
+            Direction::Left => dial.checked_sub(self.distance),
+            Direction::Right => dial.checked_add(self.distance),
+        }
+    }
+}
+
+/// how many positions in the interval (inclusive on both ends) point at `target`
+/// on a dial of `size` positions
+pub fn targets_in_interval(interval: RangeInclusive<isize>, size: isize, target: isize) -> usize {
+    let lower_class = (interval.start() - target - 1).div_euclid(size);
+    let upper_class = (interval.end() - target).div_euclid(size);
+    lower_class.abs_diff(upper_class)
+}
+
+/// a dial of `size` positions numbered from 0, which starts at `start` and watches for `targets`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dial {
+    size: isize,
+    start: isize,
+    targets: BTreeSet<isize>,
+}
+
+/// how often a dial pointed at one target
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Hits {
+    /// rotations that ended pointing at the target
+    pub landings: usize,
+    /// clicks, during any rotation, that left the dial pointing at the target
+    pub passes: usize,
+}
+
+/// which of a target's [`Hits`] to count
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HitKind {
+    Landings,
+    Passes,
+}
+
+impl Hits {
+    pub fn get(&self, kind: HitKind) -> usize {
+        match kind {
+            HitKind::Landings => self.landings,
+            HitKind::Passes => self.passes,
+        }
+    }
+}
+
+impl AddAssign for Hits {
+    fn add_assign(&mut self, rhs: Self) {
+        self.landings += rhs.landings;
+        self.passes += rhs.passes;
+    }
+}
+
+/// one rotation of a dial, as seen by [`Dial::trace`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Turn {
+    pub rotation: Rotation,
+    /// position before the rotation
+    pub before: isize,
+    /// position after the rotation
+    pub after: isize,
+    /// hits on each target during this rotation
+    pub hits: BTreeMap<isize, Hits>,
+    /// hits on each target up to and including this rotation
+    pub totals: BTreeMap<isize, Hits>,
+}
+
+impl Default for Dial {
+    /// the puzzle's dial
+    fn default() -> Self {
+        Self::new(DIAL_SIZE, DIAL_START, [0])
+    }
+}
+
+impl Dial {
+    /// `start` and `targets` wrap around the dial, so -1 is the same as `size - 1`.
+    /// Panics if `size` isn't positive.
+    pub fn new(size: isize, start: isize, targets: impl IntoIterator<Item = isize>) -> Self {
+        assert!(size > 0, "dial size must be positive, not {size}");
+        Self {
+            size,
+            start: start.rem_euclid(size),
+            targets: targets
+                .into_iter()
+                .map(|target| target.rem_euclid(size))
+                .collect(),
+        }
+    }
+
+    pub fn size(&self) -> isize {
+        self.size
+    }
+
+    pub fn start(&self) -> isize {
+        self.start
+    }
+
+    pub fn targets(&self) -> &BTreeSet<isize> {
+        &self.targets
+    }
+
+    /// turns the dial through `rotations`, counting hits on every target
+    pub fn count(&self, rotations: &[Rotation]) -> BTreeMap<isize, Hits> {
+        self.trace(rotations)
+            .last()
+            .map_or_else(|| self.no_hits(), |turn| turn.totals)
+    }
+
+    /// turns the dial through `rotations`, yielding what happened in each one
+    pub fn trace(&self, rotations: &[Rotation]) -> impl Iterator<Item = Turn> {
+        rotations
+            .iter()
+            .scan((self.start, self.no_hits()), |(dial, totals), &rotation| {
+                let before = *dial;
+                let (after, laps, clicks) = self.turn(before, rotation);
+                let mut hits = self.no_hits();
+                for (&target, hits) in &mut hits {
+                    // every full lap passes every target once
+                    hits.passes = laps;
+                    if !clicks.is_empty() {
+                        hits.passes += targets_in_interval(clicks.clone(), self.size, target);
+                    }
+                    hits.landings = usize::from(after == target);
+                    *totals.entry(target).or_default() += *hits;
+                }
+                *dial = after;
+                Some(Turn {
+                    rotation,
+                    before,
+                    after,
+                    hits,
+                    totals: totals.clone(),
+                })
+            })
+    }
+
+    /// turns the dial from `position` through `rotation` without overflowing, however far
+    /// it goes: the new position, the number of full laps, and the clicks of what's left
+    /// after them, not counting the start. The clicks may lie off the dial, a lap to the left.
+    fn turn(&self, position: isize, rotation: Rotation) -> (isize, usize, RangeInclusive<isize>) {
+        let laps = (rotation.distance / self.size).unsigned_abs();
+        let rest = Rotation {
+            distance: rotation.distance % self.size,
+            ..rotation
+        };
+        let from = match rotation.direction {
+            Direction::Left => position,
+            // a lap to the left, so that it can't overflow
+            Direction::Right => position - self.size,
+        };
+        let to = rest.apply(from).expect("less than a lap from the dial");
+        let clicks = match rotation.direction {
+            Direction::Left => to..=from - 1,
+            Direction::Right => from + 1..=to,
+        };
+        (to.rem_euclid(self.size), laps, clicks)
+    }
+
+    fn no_hits(&self) -> BTreeMap<isize, Hits> {
+        self.targets
+            .iter()
+            .map(|&target| (target, Hits::default()))
+            .collect()
+    }
+
+    /// every start position from which `rotations` hit `target` exactly `n` times.
+    /// Ignores this dial's own start and targets.
+    pub fn starts_reaching(
+        &self,
+        rotations: &[Rotation],
+        target: isize,
+        kind: HitKind,
+        n: usize,
+    ) -> Vec<isize> {
+        (0..self.size)
+            .zip(self.hits_by_start(rotations, target.rem_euclid(self.size)))
+            .filter(|(_, hits)| hits.get(kind) == n)
+            .map(|(start, _)| start)
+            .collect()
+    }
+
+    /// hits on `target` from each start position, without turning the dial from each.
+    ///
+    /// Relative to the start, the dial takes the same path whatever the start is.
+    /// A rotation from any start lands on the target from exactly one start, and
+    /// passes it once per full lap, plus once more from the starts in a run as long
+    /// as the leftover part of a lap.
+    fn hits_by_start(&self, rotations: &[Rotation], target: isize) -> Vec<Hits> {
+        let size = self.size.unsigned_abs();
+        let mut landings = vec![0; size];
+        let mut full_laps = 0;
+        // differences between the partial laps' passes from consecutive starts
+        let mut partial_passes = vec![0_isize; size + 1];
+        let mut offset = 0;
+        for &rotation in rotations {
+            let (new_offset, laps, clicks) = self.turn(offset, rotation);
+            landings[(target - new_offset).rem_euclid(self.size).unsigned_abs()] += 1;
+            full_laps += laps;
+            let leftover = (clicks.end() - clicks.start() + 1).max(0).unsigned_abs();
+            if leftover > 0 {
+                // from these starts, the target comes within the first `leftover` clicks
+                let first = (target - clicks.start() - leftover as isize + 1).rem_euclid(self.size);
+                let first = first.unsigned_abs();
+                partial_passes[first] += 1;
+                if first + leftover <= size {
+                    partial_passes[first + leftover] -= 1;
+                } else {
+                    partial_passes[0] += 1;
+                    partial_passes[first + leftover - size] -= 1;
+                }
+            }
+            offset = new_offset;
+        }
+        landings
+            .into_iter()
+            .zip(partial_passes.into_iter().scan(0, |passes, diff| {
+                *passes += diff;
+                Some(*passes)
+            }))
+            .map(|(landings, partial)| Hits {
+                landings,
+                passes: full_laps + partial.unsigned_abs(),
+            })
+            .collect()
+    }
+
+    /// the shortest rotation that, appended to `rotations`, makes them hit `target`
+    /// exactly `n` times; `None` if no single rotation can.
+    /// Ignores this dial's own targets.
+    pub fn rotation_reaching(
+        &self,
+        rotations: &[Rotation],
+        target: isize,
+        kind: HitKind,
+        n: usize,
+    ) -> Option<Rotation> {
+        let target = target.rem_euclid(self.size);
+        let single = Self::new(self.size, self.start, [target]);
+        let so_far = single.count(rotations)[&target].get(kind);
+        let needed = n.checked_sub(so_far)?;
+        let position = rotations
+            .iter()
+            .fold(self.start, |dial, &rotation| self.turn(dial, rotation).0);
+        let rotation = |direction, distance| Rotation {
+            direction,
+            distance,
+        };
+        // clicks to the target going each way, possibly none
+        let right = (target - position).rem_euclid(self.size);
+        let left = (position - target).rem_euclid(self.size);
+        let shorter = |right, left| {
+            if right <= left {
+                rotation(Direction::Right, right)
+            } else {
+                rotation(Direction::Left, left)
+            }
+        };
+        match (kind, needed) {
+            (_, 0) if kind == HitKind::Passes || position != target => {
+                Some(rotation(Direction::Right, 0))
+            }
+            // anywhere else, unless there is nowhere else
+            (HitKind::Landings, 0) => (self.size > 1).then(|| rotation(Direction::Right, 1)),
+            (HitKind::Landings, 1) => Some(shorter(right, left)),
+            (HitKind::Landings, _) => None,
+            (HitKind::Passes, needed) => {
+                // a rotation doesn't pass where it starts, so then the first pass is a lap away
+                let laps = isize::try_from(needed - 1).ok()?.checked_mul(self.size)?;
+                let first = |clicks| if clicks == 0 { self.size } else { clicks };
+                let right = laps.checked_add(first(right))?;
+                let left = laps.checked_add(first(left))?;
+                Some(shorter(right, left))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        DIAL_SIZE, Dial, Direction, HitKind, Hits, Rotation, RotationParseError, Turn,
+        targets_in_interval,
+    };
+
+    #[test]
+    fn test_rotation_parse() {
+        let rotation: Rotation = "L68".parse().unwrap();
+        assert_eq!(
+            rotation,
+            Rotation {
+                direction: Direction::Left,
+                distance: 68
+            }
+        );
+        assert_eq!(rotation.to_string(), "L68");
+        for s in ["L 68", "left68", "Left 68", " l68\r", "-68"] {
+            assert_eq!(s.parse::<Rotation>().unwrap(), rotation, "{s:?}");
+        }
+        for s in ["R5", "RIGHT 5", "+5", "5"] {
+            assert_eq!(s.parse::<Rotation>().unwrap().to_string(), "R5", "{s:?}");
+        }
+    }
+
+    #[test]
+    fn test_rotation_parse_errors() {
+        let err = |s| Rotation::parse_spanned(s).unwrap_err();
+        assert!(matches!(err("  "), (RotationParseError::Empty, "")));
+        assert!(matches!(err("X5"), (RotationParseError::Direction(_), "X")));
+        assert!(matches!(err("é5"), (RotationParseError::Direction(_), "é")));
+        assert!(matches!(
+            err("Up 5"),
+            (RotationParseError::Direction(_), "Up")
+        ));
+        assert!(matches!(
+            err("L"),
+            (RotationParseError::MissingDistance, "")
+        ));
+        assert!(matches!(
+            err("L -5"),
+            (RotationParseError::SignedDistance, "-")
+        ));
+        assert!(matches!(
+            err("Rx"),
+            (RotationParseError::Direction(_), "Rx")
+        ));
+        assert!(matches!(
+            err("R5x"),
+            (RotationParseError::Distance(_), "5x")
+        ));
+        assert!(matches!(
+            err("R5 6"),
+            (RotationParseError::Distance(_), "5 6")
+        ));
+        assert!(matches!(
+            err("--5"),
+            (RotationParseError::Distance(_), "--5")
+        ));
+        assert!(matches!(
+            err("-9223372036854775808"),
+            (RotationParseError::DistanceOverflow, "-9223372036854775808")
+        ));
+    }
+
+    #[test]
+    fn test_rotation_apply() {
+        let left: Rotation = "L68".parse().unwrap();
+        let right: Rotation = "R48".parse().unwrap();
+        assert_eq!(left.apply(50), Some(-18));
+        assert_eq!(left.apply(50).unwrap().rem_euclid(100), 82);
+        assert_eq!(right.apply(52), Some(100));
+        let far: Rotation = "R9223372036854775807".parse().unwrap();
+        assert_eq!(far.apply(1), None);
+    }
+
+    #[test]
+    fn test_targets_in_interval() {
+        let zeros = |interval| targets_in_interval(interval, DIAL_SIZE, 0);
+        assert_eq!(zeros(1..=99), 0);
+        assert_eq!(zeros(0..=99), 1);
+        assert_eq!(zeros(-18..=50), 1);
+        assert_eq!(zeros(50..=1050), 10);
+        assert_eq!(zeros(-100..=0), 2);
+        assert_eq!(targets_in_interval(-18..=50, 10, 3), 7);
+    }
+
+    #[test]
+    fn test_dial_count() {
+        let rotations: Vec<Rotation> = ["L68", "L30", "R48", "L5", "R60", "L55", "L1", "L99"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        let hits = Dial::default().count(&rotations);
+        assert_eq!(
+            hits[&0],
+            Hits {
+                landings: 3,
+                passes: 5
+            }
+        );
+
+        let hits = Dial::new(10, 0, [0, 2, -1]).count(&rotations);
+        assert_eq!(hits.keys().copied().collect::<Vec<_>>(), vec![0, 2, 9]);
+        // 0 -> 2 -> 2 -> 0 -> 5 -> 5 -> 0 -> 9 -> 0
+        assert_eq!(hits[&0].landings, 3);
+        assert_eq!(hits[&2].landings, 2);
+        assert_eq!(hits[&9].landings, 1);
+        assert_eq!(hits[&0].passes, 36);
+        assert_eq!(hits[&9].passes, 37);
+
+        // as far as a distance goes, each way
+        let far: Vec<Rotation> = ["L9223372036854775807", "R9223372036854775807"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        let turns = Dial::default().trace(&far).collect::<Vec<_>>();
+        assert_eq!((turns[0].after, turns[1].after), (43, 50));
+        assert_eq!(
+            turns[1].totals[&0],
+            Hits {
+                landings: 0,
+                passes: 184_467_440_737_095_516
+            }
+        );
+        let starts = Dial::default().starts_reaching(&far, 0, HitKind::Landings, 1);
+        assert_eq!(starts, vec![0, 7]);
+    }
+
+    #[test]
+    fn test_dial_trace() {
+        let rotations: Vec<Rotation> = ["L68", "R18", "L0"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        let turns: Vec<Turn> = Dial::default().trace(&rotations).collect();
+        let summary = turns
+            .iter()
+            .map(|turn| (turn.before, turn.after, turn.hits[&0], turn.totals[&0]))
+            .collect::<Vec<_>>();
+        let hits = |landings, passes| Hits { landings, passes };
+        assert_eq!(
+            summary,
+            vec![
+                (50, 82, hits(0, 1), hits(0, 1)),
+                (82, 0, hits(1, 1), hits(1, 2)),
+                (0, 0, hits(1, 0), hits(2, 2)),
+            ]
+        );
+        assert_eq!(turns[1].rotation.to_string(), "R18");
+    }
+
+    #[test]
+    fn test_starts_reaching() {
+        let rotations: Vec<Rotation> = include_str!("../fixtures/01.txt")
+            .lines()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        for (size, target) in [(100, 0), (7, 3), (1, 0)] {
+            let dial = Dial::new(size, 0, []);
+            let hits = (0..size)
+                .map(|start| Dial::new(size, start, [target]).count(&rotations)[&target])
+                .collect::<Vec<_>>();
+            for kind in [HitKind::Landings, HitKind::Passes] {
+                for n in 0..12 {
+                    let expected = (0..size)
+                        .filter(|&start| hits[start as usize].get(kind) == n)
+                        .collect::<Vec<_>>();
+                    assert_eq!(
+                        dial.starts_reaching(&rotations, target, kind, n),
+                        expected,
+                        "size {size}, {kind:?} {n}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotation_reaching() {
+        let rotations: Vec<Rotation> = ["L68", "L30", "R48"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        let dial = Dial::default();
+        // at 0 after landing once and passing twice
+        let reaching = |kind, n| {
+            dial.rotation_reaching(&rotations, 0, kind, n)
+                .map(|rotation| rotation.to_string())
+        };
+        assert_eq!(reaching(HitKind::Landings, 0), None);
+        assert_eq!(reaching(HitKind::Landings, 1).as_deref(), Some("R1"));
+        assert_eq!(reaching(HitKind::Landings, 2).as_deref(), Some("R0"));
+        assert_eq!(reaching(HitKind::Landings, 3), None);
+        assert_eq!(reaching(HitKind::Passes, 1), None);
+        assert_eq!(reaching(HitKind::Passes, 2).as_deref(), Some("R0"));
+        assert_eq!(reaching(HitKind::Passes, 4).as_deref(), Some("R200"));
+
+        let dial = Dial::new(100, 10, []);
+        let reaching = |kind, n| dial.rotation_reaching(&[], 0, kind, n).unwrap();
+        assert_eq!(reaching(HitKind::Landings, 1).to_string(), "L10");
+        assert_eq!(reaching(HitKind::Passes, 3).to_string(), "L210");
+        for n in 0..5 {
+            let rotation = reaching(HitKind::Passes, n);
+            let hits = Dial::new(100, 10, [0]).count(&[rotation])[&0];
+            assert_eq!(hits.passes, n);
+        }
+    }
+}
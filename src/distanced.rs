@@ -0,0 +1,26 @@
+/// ignores element value for comparison
+pub struct Distanced<T> {
+    pub distance: f64,
+    pub element: T,
+}
+
+impl<T> PartialEq for Distanced<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance == other.distance
+    }
+}
+
+impl<T> Eq for Distanced<T> {}
+
+impl<T> PartialOrd for Distanced<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Distanced<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // reverse order so that the max heap will put minimum distances first
+        other.distance.total_cmp(&self.distance)
+    }
+}
@@ -0,0 +1,19 @@
+//! where puzzle inputs live on disk
+
+use std::path::{Path, PathBuf};
+
+/// a day's puzzle input in `dir`, named by two-digit day, like `08.txt`
+pub fn input_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("{day:02}.txt"))
+}
+
+/// a part's example in `dir` is `<day>-<part>.txt` if the parts have different examples,
+/// and `<day>.txt` otherwise
+pub fn example_path(dir: &Path, day: u8, part: usize) -> PathBuf {
+    let for_part = dir.join(format!("{day:02}-{part}.txt"));
+    if for_part.exists() {
+        for_part
+    } else {
+        input_path(dir, day)
+    }
+}
@@ -0,0 +1,12 @@
+pub mod answers;
+pub mod cephalop;
+pub mod days;
+pub mod dial;
+pub mod distanced;
+pub mod inputs;
+pub mod parse;
+pub mod point;
+pub mod position;
+pub mod range_set;
+pub mod solution;
+pub mod union_find;
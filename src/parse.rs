@@ -0,0 +1,177 @@
+use std::{fmt::Display, ops::RangeInclusive, str::FromStr};
+
+/// a malformed puzzle input, pointing at the text that could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "line {line}, column {column}: expected {expected}, found {}",
+    Found(found)
+)]
+pub struct ParseError {
+    /// 1-based
+    pub line: usize,
+    /// 1-based, counted in characters
+    pub column: usize,
+    pub found: String,
+    pub expected: String,
+}
+
+struct Found<'a>(&'a str);
+
+impl Display for Found<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            write!(f, "nothing")
+        } else {
+            write!(f, "{:?}", self.0)
+        }
+    }
+}
+
+/// 1-based column of `found` within `text`, which it must be a slice of
+fn column(text: &str, found: &str) -> usize {
+    let offset = (found.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+    debug_assert!(offset <= text.len(), "{found:?} is not a slice of {text:?}");
+    text.get(..offset)
+        .map_or(0, |before| before.chars().count())
+        + 1
+}
+
+impl ParseError {
+    /// an error in a single line of text, such as the input to a `FromStr`;
+    /// `found` must be a slice of `text`. Reported as line 1 until the caller
+    /// places it with [`Line::parse_with`].
+    pub fn within(text: &str, found: &str, expected: impl Into<String>) -> Self {
+        Self {
+            line: 1,
+            column: column(text, found),
+            found: found.to_owned(),
+            expected: expected.into(),
+        }
+    }
+
+    /// an error at the very end of the input, where more was expected
+    pub fn end_of_input(input: &str, expected: impl Into<String>) -> Self {
+        Self {
+            line: input.lines().count() + 1,
+            column: 1,
+            found: String::new(),
+            expected: expected.into(),
+        }
+    }
+}
+
+/// one line of puzzle input, which remembers where it came from for error reporting
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    /// 1-based
+    pub number: usize,
+    pub text: &'a str,
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+impl Line<'_> {
+    /// `found` must be a slice of this line's text
+    pub fn error(&self, found: &str, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.number,
+            ..ParseError::within(self.text, found, expected)
+        }
+    }
+
+    /// `field` must be a slice of this line's text
+    pub fn parse<T: FromStr>(&self, field: &str, expected: &str) -> Result<T, ParseError> {
+        field.parse().map_err(|_| self.error(field, expected))
+    }
+
+    /// parses `field` with a `FromStr` that reports its own errors relative to the field,
+    /// moving them to where the field sits in this line
+    pub fn parse_with<T: FromStr<Err = ParseError>>(&self, field: &str) -> Result<T, ParseError> {
+        field.parse().map_err(|err: ParseError| ParseError {
+            line: self.number,
+            column: column(self.text, field) + err.column - 1,
+            ..err
+        })
+    }
+
+    /// parses an inclusive range written like `11-22`, or `-5--3` with negative bounds
+    pub fn parse_range<T: FromStr>(&self, field: &str) -> Result<RangeInclusive<T>, ParseError> {
+        // the separator is the first `-` that can't be the first bound's sign
+        let (first_str, last_str) = field
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| c == '-')
+            .map(|(i, _)| (&field[..i], &field[i + 1..]))
+            .ok_or_else(|| self.error(field, "a range like 11-22"))?;
+        let first = self.parse(first_str, "a number")?;
+        let last = self.parse(last_str, "a number")?;
+        Ok(first..=last)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ParseError, lines};
+
+    #[test]
+    fn test_line_error() {
+        let line = lines("12-34\n5-x\n").nth(1).unwrap();
+        assert_eq!(
+            line.parse_range::<usize>(line.text),
+            Err(ParseError {
+                line: 2,
+                column: 3,
+                found: "x".to_owned(),
+                expected: "a number".to_owned(),
+            })
+        );
+        assert_eq!(
+            line.parse_range::<usize>(line.text)
+                .unwrap_err()
+                .to_string(),
+            r#"line 2, column 3: expected a number, found "x""#
+        );
+    }
+
+    #[test]
+    fn test_parse_signed_range() {
+        let line = lines("-5--3").next().unwrap();
+        assert_eq!(line.parse_range::<i64>(line.text), Ok(-5..=-3));
+        let line = lines("-5-3").next().unwrap();
+        assert_eq!(line.parse_range::<i64>(line.text), Ok(-5..=3));
+        let line = lines("-5").next().unwrap();
+        let err = line.parse_range::<i64>(line.text).unwrap_err();
+        assert_eq!(err.expected, "a range like 11-22");
+    }
+
+    #[test]
+    fn test_column_counts_characters() {
+        let text = "é,x";
+        let err = ParseError::within(text, &text[3..], "a digit");
+        assert_eq!(err.column, 3);
+    }
+
+    #[test]
+    fn test_parse_with_relocates() {
+        #[derive(Debug)]
+        struct Digit;
+        impl std::str::FromStr for Digit {
+            type Err = ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s.find(|c: char| !c.is_ascii_digit()) {
+                    Some(i) => Err(ParseError::within(s, &s[i..i + 1], "a digit")),
+                    None => Ok(Digit),
+                }
+            }
+        }
+        let line = lines("a\nfoo: 12x4").nth(1).unwrap();
+        let err = line.parse_with::<Digit>(&line.text[5..]).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 8));
+        assert_eq!(err.found, "x");
+    }
+}
@@ -0,0 +1,42 @@
+use std::str::FromStr;
+
+use crate::parse::ParseError;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split(',');
+        let mut coordinate = |expected: &str| {
+            let field = fields
+                .next()
+                .ok_or_else(|| ParseError::within(s, &s[s.len()..], expected))?;
+            field
+                .parse()
+                .map_err(|_| ParseError::within(s, field, expected))
+        };
+        let x = coordinate("an x coordinate")?;
+        let y = coordinate("a y coordinate")?;
+        let z = coordinate("a z coordinate")?;
+        if let Some(extra) = fields.next() {
+            return Err(ParseError::within(s, extra, "only three coordinates"));
+        }
+        Ok(Self { x, y, z })
+    }
+}
+
+impl Point {
+    pub fn distance(&self, other: &Self) -> f64 {
+        let x_diff = self.x.abs_diff(other.x) as f64;
+        let y_diff = self.y.abs_diff(other.y) as f64;
+        let z_diff = self.z.abs_diff(other.z) as f64;
+        (x_diff.powi(2) + y_diff.powi(2) + z_diff.powi(2)).sqrt()
+    }
+}
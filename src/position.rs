@@ -0,0 +1,34 @@
+use itertools::Itertools;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Position {
+    /// the up-to-eight neighbors of this position, clipped at row and column 0
+    pub fn adjacent(&self) -> impl Iterator<Item = Self> {
+        let rows = [self.row, self.row + 1]
+            .into_iter()
+            .chain(self.row.checked_sub(1));
+        let cols = [self.col, self.col + 1]
+            .into_iter()
+            .chain(self.col.checked_sub(1));
+        rows.cartesian_product(cols)
+            .map(|(row, col)| Self { row, col })
+            .filter(move |pos| pos != self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Position;
+
+    #[test]
+    fn test_adjacent() {
+        assert_eq!(Position { row: 5, col: 5 }.adjacent().count(), 8);
+        assert_eq!(Position { row: 0, col: 5 }.adjacent().count(), 5);
+        assert_eq!(Position { row: 0, col: 0 }.adjacent().count(), 3);
+    }
+}
@@ -0,0 +1,736 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    ops::{Bound, RangeInclusive},
+    str::FromStr,
+};
+
+use itertools::Itertools;
+
+use crate::parse::{ParseError, lines};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RangeBoundary {
+    /// inclusive
+    Start,
+    /// exclusive
+    End,
+}
+
+/// integer-like types that a [`RangeSet`] can hold
+pub trait Step: Copy + Ord {
+    const MIN: Self;
+    /// the next value up; panics if there isn't one
+    fn forward(self) -> Self;
+    /// the next value down; panics if there isn't one
+    fn backward(self) -> Self;
+    /// number of values in `start..end`, which always fits since no type is wider than `u128`
+    fn distance(start: Self, end: Self) -> u128;
+    /// the value `n` up, if there is one
+    fn forward_by(self, n: u128) -> Option<Self>;
+}
+
+/// implements [`Step`] for each type, stepping through `$wide`, which must hold all their values
+macro_rules! impl_step {
+    ($wide:ty: $($t:ty),*) => {$(
+        impl Step for $t {
+            const MIN: Self = <$t>::MIN;
+
+            fn forward(self) -> Self {
+                self.checked_add(1).expect("range end overflows")
+            }
+
+            fn backward(self) -> Self {
+                self.checked_sub(1).expect("range start underflows")
+            }
+
+            fn distance(start: Self, end: Self) -> u128 {
+                end.abs_diff(start) as u128
+            }
+
+            fn forward_by(self, n: u128) -> Option<Self> {
+                let sum = <$wide>::try_from(self).ok()?.checked_add(<$wide>::try_from(n).ok()?)?;
+                Self::try_from(sum).ok()
+            }
+        }
+    )*};
+}
+
+impl_step!(i128: u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_step!(u128: u128);
+
+/// a set of integers, stored as disjoint ranges.
+/// Ranges are stored with exclusive ends, so the set can't hold `T::MAX`.
+///
+/// invariants:
+/// - for every start bound, the next element is always its end bound
+/// - for every end bount, the previous element is always it start bound
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RangeSet<T>(Inner<T>);
+type Inner<T> = BTreeMap<T, RangeBoundary>;
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self(BTreeMap::new())
+    }
+}
+
+impl<T: Step> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// panics if `range` ends at `T::MAX`, which the set can't hold; see [`Self::try_add`]
+    pub fn add(&mut self, range: RangeInclusive<T>) {
+        self.try_add(range)
+            .expect("range ends before the largest value");
+    }
+
+    /// adds `range`, unless it ends at `T::MAX`, which the set can't hold
+    pub fn try_add(&mut self, range: RangeInclusive<T>) -> Result<(), EndsAtMax> {
+        if range.is_empty() {
+            return Ok(());
+        }
+        let carve_range = *range.start()..=range.end().forward_by(1).ok_or(EndsAtMax)?;
+        self.0
+            .extract_if(carve_range.clone(), |_, _| true)
+            .for_each(drop);
+        let starts_in_existing_range = self
+            .0
+            .range(..=carve_range.start())
+            .next_back()
+            .is_some_and(|(_, &b)| matches!(b, RangeBoundary::Start));
+        let ends_in_existing_range = self
+            .0
+            .range(carve_range.end()..)
+            .next()
+            .is_some_and(|(_, &b)| matches!(b, RangeBoundary::End));
+        if !starts_in_existing_range {
+            let clobbered = self.0.insert(*range.start(), RangeBoundary::Start);
+            debug_assert!(clobbered.is_none())
+        }
+        if !ends_in_existing_range {
+            let clobbered = self.0.insert(*carve_range.end(), RangeBoundary::End);
+            debug_assert!(clobbered.is_none())
+        }
+        Ok(())
+    }
+
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        if range.is_empty() {
+            return;
+        }
+        // `None` if the range runs to `T::MAX`, past everything the set can hold
+        let (start, end) = (*range.start(), range.end().forward_by(1));
+        self.0.extract_if(range, |_, _| true).for_each(drop);
+        let starts_in_existing_range = self
+            .0
+            .range(..start)
+            .next_back()
+            .is_some_and(|(_, &b)| matches!(b, RangeBoundary::Start));
+        if starts_in_existing_range {
+            self.0.insert(start, RangeBoundary::End);
+        }
+        let Some(end) = end else {
+            return;
+        };
+        match self.0.range(end..).next() {
+            // the range that ran on past `range` ended right after it, so nothing is left
+            Some((&i, RangeBoundary::End)) if i == end => {
+                self.0.remove(&end);
+            }
+            Some((_, RangeBoundary::End)) => {
+                self.0.insert(end, RangeBoundary::Start);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn contains(&self, elt: T) -> bool {
+        self.0
+            .range(..=elt)
+            .next_back()
+            .is_some_and(|(_, &b)| matches!(b, RangeBoundary::Start))
+    }
+
+    /// pairs each of `elts`, in order, with the range of the set containing it, if any.
+    /// Sorts the queries (unless they already are) and walks them alongside the ranges,
+    /// rather than searching the ranges for each one.
+    pub fn locate_all(&self, elts: &[T]) -> Vec<(T, Option<RangeInclusive<T>>)> {
+        let mut order = (0..elts.len()).collect_vec();
+        if !elts.is_sorted() {
+            order.sort_by_key(|&i| elts[i]);
+        }
+        let mut located = vec![None; elts.len()];
+        let mut ranges = self.iter().peekable();
+        for i in order {
+            while ranges.next_if(|range| *range.end() < elts[i]).is_some() {}
+            located[i] = ranges
+                .peek()
+                .filter(|range| range.contains(&elts[i]))
+                .cloned();
+        }
+        elts.iter().copied().zip(located).collect()
+    }
+
+    /// those of `elts` that are in the set, in order
+    pub fn contains_all(&self, elts: &[T]) -> Vec<T> {
+        self.locate_all(elts)
+            .into_iter()
+            .filter_map(|(elt, range)| range.map(|_| elt))
+            .collect()
+    }
+
+    /// number of integers in `range` that are also in the set
+    pub fn count_overlap(&self, range: RangeInclusive<T>) -> u128 {
+        if range.is_empty() {
+            return 0;
+        }
+        // `None` if the range runs to `T::MAX`, past everything the set can hold
+        let (start, end) = (*range.start(), range.end().forward_by(1));
+        let end_bound = end.map_or(Bound::Unbounded, Bound::Excluded);
+        let mut covered = 0;
+        let mut covered_from = self.contains(start).then_some(start);
+        for (&i, bound) in self.0.range((Bound::Excluded(start), end_bound)) {
+            match bound {
+                RangeBoundary::Start => covered_from = Some(i),
+                RangeBoundary::End => {
+                    covered += T::distance(covered_from.take().expect("start before end"), i)
+                }
+            }
+        }
+        covered
+            + covered_from
+                .zip(end)
+                .map_or(0, |(from, end)| T::distance(from, end))
+    }
+
+    /// the disjoint, non-adjacent ranges making up the set, in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.0
+            .iter()
+            .tuples()
+            .map(|((&start_i, start_bound), (&end_i, end_bound))| {
+                debug_assert_eq!(*start_bound, RangeBoundary::Start);
+                debug_assert_eq!(*end_bound, RangeBoundary::End);
+                start_i..=end_i.backward()
+            })
+    }
+
+    /// total number of integers covered by the set
+    pub fn len(&self) -> u128 {
+        self.iter()
+            .map(|range| T::distance(*range.start(), *range.end()) + 1)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// integers in either set
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        other.iter().for_each(|range| union.add(range));
+        union
+    }
+
+    /// integers in `self` but not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        other.iter().for_each(|range| difference.remove(range));
+        difference
+    }
+
+    /// integers in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        // whatever is left of `self` after taking away everything that isn't in `other`
+        self.difference(&self.difference(other))
+    }
+
+    /// integers within `bounds` that aren't in the set, short of `T::MAX`, which no set can hold
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        let (start, end) = bounds.into_inner();
+        let end = match end.forward_by(1) {
+            Some(_) => end,
+            None => end.backward(),
+        };
+        Self::from_iter([start..=end]).difference(self)
+    }
+}
+
+/// panics if any range ends at `T::MAX`, like [`RangeSet::add`]
+impl<T: Step> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut ranges = Self::new();
+        iter.into_iter().for_each(|range| ranges.add(range));
+        ranges
+    }
+}
+
+/// one inclusive range per line, like `3-5`, as in the puzzle input
+impl<T: Step + Display> Display for RangeSet<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for range in self.iter() {
+            writeln!(f, "{}-{}", range.start(), range.end())?;
+        }
+        Ok(())
+    }
+}
+
+/// parses what [`Display`] writes: ranges in ascending order that don't overlap
+impl<T: Step + FromStr> FromStr for RangeSet<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ranges = Self::new();
+        let mut last_end = None;
+        for line in lines(s).filter(|line| !line.text.is_empty()) {
+            let range: RangeInclusive<T> = line.parse_range(line.text)?;
+            if range.is_empty() {
+                return Err(line.error(line.text, "a range that ends after it starts"));
+            }
+            if range.end().forward_by(1).is_none() {
+                let expected = "a range that ends before the largest value";
+                return Err(line.error(line.text, expected));
+            }
+            if last_end.is_some_and(|end| end >= *range.start()) {
+                let expected = "a range that starts after the previous one ends";
+                return Err(line.error(line.text, expected));
+            }
+            last_end = Some(*range.end());
+            ranges.add(range);
+        }
+        Ok(ranges)
+    }
+}
+
+/// a range that [`RangeSet::try_add`] couldn't add, because it ends at `T::MAX`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("Range ends at the largest value, which a range set can't hold")]
+pub struct EndsAtMax;
+
+/// a malformed binary encoding of a [`RangeSet`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DecodeError {
+    #[error("Encoding ends partway through a range")]
+    Truncated,
+    #[error("Boundary {0} is out of range")]
+    Overflow(usize),
+    #[error("Boundary {0} does not come after the previous one")]
+    Unordered(usize),
+}
+
+impl<T: Step> RangeSet<T> {
+    /// a compact binary encoding: every boundary (inclusive start, then exclusive end) as
+    /// an unsigned LEB128 of its distance from the previous one, or from `T::MIN` for the first
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        let mut prev = T::MIN;
+        for &i in self.0.keys() {
+            let mut delta = T::distance(prev, i);
+            loop {
+                let byte = (delta & 0x7f) as u8;
+                delta >>= 7;
+                if delta == 0 {
+                    bytes.push(byte);
+                    break;
+                }
+                bytes.push(byte | 0x80);
+            }
+            prev = i;
+        }
+        bytes
+    }
+
+    /// reverses [`Self::encode`], checking that the ranges are in order and don't overlap
+    pub fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let mut inner = Inner::new();
+        let mut bytes = bytes.iter();
+        let mut prev = T::MIN;
+        let mut boundary = 0;
+        while bytes.len() > 0 {
+            let mut delta: u128 = 0;
+            let mut shift = 0;
+            loop {
+                let &byte = bytes.next().ok_or(DecodeError::Truncated)?;
+                delta |= u128::from(byte & 0x7f)
+                    .checked_shl(shift)
+                    .filter(|bits| bits >> shift == u128::from(byte & 0x7f))
+                    .ok_or(DecodeError::Overflow(boundary))?;
+                shift += 7;
+                if byte & 0x80 == 0 {
+                    break;
+                }
+            }
+            if boundary > 0 && delta == 0 {
+                return Err(DecodeError::Unordered(boundary));
+            }
+            let i = prev
+                .forward_by(delta)
+                .ok_or(DecodeError::Overflow(boundary))?;
+            let bound = match boundary % 2 {
+                0 => RangeBoundary::Start,
+                _ => RangeBoundary::End,
+            };
+            inner.insert(i, bound);
+            prev = i;
+            boundary += 1;
+        }
+        if boundary % 2 == 1 {
+            return Err(DecodeError::Truncated);
+        }
+        Ok(Self(inner))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::BTreeSet, ops::RangeInclusive};
+
+    use itertools::Itertools;
+    use proptest::prelude::*;
+
+    use super::{DecodeError, EndsAtMax, RangeBoundary, RangeSet};
+    use crate::parse::ParseError;
+
+    /// small enough that random ranges often overlap or touch
+    const MAX: usize = 64;
+
+    #[derive(Clone, Debug)]
+    enum Op {
+        Add(RangeInclusive<usize>),
+        Remove(RangeInclusive<usize>),
+        Contains(usize),
+    }
+
+    fn range() -> impl Strategy<Value = RangeInclusive<usize>> {
+        (0..MAX, 0..8_usize).prop_map(|(start, len)| start..=start + len)
+    }
+
+    fn op() -> impl Strategy<Value = Op> {
+        prop_oneof![
+            range().prop_map(Op::Add),
+            range().prop_map(Op::Remove),
+            (0..MAX + 8).prop_map(Op::Contains),
+        ]
+    }
+
+    /// a `RangeSet` and the same set of integers, one by one
+    fn sets() -> impl Strategy<Value = (RangeSet<usize>, BTreeSet<usize>)> {
+        prop::collection::vec(range(), 0..8).prop_map(|ranges| {
+            let model = ranges.iter().cloned().flatten().collect();
+            (RangeSet::from_iter(ranges), model)
+        })
+    }
+
+    fn assert_invariants(ranges: &RangeSet<usize>) {
+        assert_eq!(ranges.0.len() % 2, 0, "{ranges:?}");
+        for ((start_i, start_bound), (end_i, end_bound)) in ranges.0.iter().tuples() {
+            assert_eq!(*start_bound, RangeBoundary::Start, "{ranges:?}");
+            assert_eq!(*end_bound, RangeBoundary::End, "{ranges:?}");
+            assert!(start_i < end_i, "{ranges:?}");
+        }
+    }
+
+    fn assert_matches(ranges: &RangeSet<usize>, model: &BTreeSet<usize>) {
+        assert_invariants(ranges);
+        let elts = ranges.iter().flatten().collect::<BTreeSet<_>>();
+        assert_eq!(&elts, model, "{ranges:?}");
+        assert_eq!(ranges.len(), model.len() as u128, "{ranges:?}");
+        // merged, so no two ranges touch
+        for (a, b) in ranges.iter().tuple_windows() {
+            assert!(a.end() + 1 < *b.start(), "{ranges:?}");
+        }
+    }
+
+    #[test]
+    fn test_add_merges() {
+        let mut ranges = RangeSet::new();
+        ranges.add(3..=5);
+        ranges.add(10..=14);
+        ranges.add(16..=20);
+        ranges.add(12..=18);
+        assert_eq!(ranges.to_string(), "3-5\n10-20\n");
+        assert_eq!(ranges.len(), 14);
+    }
+
+    #[test]
+    fn test_add_adjacent() {
+        let mut ranges = RangeSet::new();
+        ranges.add(1..=2);
+        ranges.add(3..=4);
+        assert_eq!(ranges.to_string(), "1-4\n");
+        ranges.add(0..=0);
+        assert_eq!(ranges.to_string(), "0-4\n");
+    }
+
+    #[test]
+    fn test_add_wide() {
+        // past i128::MAX
+        let top = u128::MAX - 1;
+        let ranges = RangeSet::from_iter([top - 9..=top - 5, top - 4..=top, top - 30..=top - 20]);
+        assert_eq!(
+            ranges.iter().collect_vec(),
+            vec![top - 30..=top - 20, top - 9..=top]
+        );
+        assert_eq!(ranges.len(), 21);
+        assert_eq!(
+            RangeSet::from_iter([0_u128..=1 << 100]).len(),
+            (1 << 100) + 1
+        );
+    }
+
+    #[test]
+    fn test_try_add() {
+        let mut ranges = RangeSet::new();
+        assert_eq!(ranges.try_add(250_u8..=254), Ok(()));
+        assert_eq!(ranges.try_add(0..=255), Err(EndsAtMax));
+        assert_eq!(ranges.try_add(255..=255), Err(EndsAtMax));
+        assert_eq!(ranges.to_string(), "250-254\n");
+    }
+
+    #[test]
+    fn test_contains() {
+        let mut ranges = RangeSet::new();
+        ranges.add(3..=5);
+        ranges.add(10..=14);
+        assert!(!ranges.contains(2));
+        assert!(ranges.contains(3));
+        assert!(ranges.contains(5));
+        assert!(!ranges.contains(6));
+        assert!(ranges.contains(14));
+        assert!(!ranges.contains(15));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut ranges = RangeSet::from_iter([0..=9, 20..=29]);
+        ranges.remove(3..=4);
+        assert_eq!(ranges.to_string(), "0-2\n5-9\n20-29\n");
+        ranges.remove(8..=24);
+        assert_eq!(ranges.to_string(), "0-2\n5-7\n25-29\n");
+        ranges.remove(5..=7);
+        ranges.remove(29..=29);
+        assert_eq!(ranges.to_string(), "0-2\n25-28\n");
+        ranges.remove(-5..=40);
+        assert!(ranges.is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = RangeSet::from_iter([0_u8..=9, 20..=29]);
+        let b = RangeSet::from_iter([5..=24]);
+        assert_eq!(a.union(&b).iter().collect::<Vec<_>>(), vec![0..=29]);
+        assert_eq!(
+            a.intersection(&b).iter().collect::<Vec<_>>(),
+            vec![5..=9, 20..=24]
+        );
+        assert_eq!(
+            a.difference(&b).iter().collect::<Vec<_>>(),
+            vec![0..=4, 25..=29]
+        );
+        assert_eq!(
+            a.complement(0..=99).iter().collect::<Vec<_>>(),
+            vec![10..=19, 30..=99]
+        );
+        assert_eq!(a.complement(0..=99).len() + a.len(), 100);
+
+        // all of u8, though the set can't hold 255
+        assert_eq!(
+            a.complement(0..=u8::MAX).iter().collect::<Vec<_>>(),
+            vec![10..=19, 30..=254]
+        );
+        let mut top = RangeSet::from_iter([240_u8..=254]);
+        assert_eq!(top.count_overlap(0..=u8::MAX), 15);
+        assert_eq!(top.count_overlap(250..=u8::MAX), 5);
+        top.remove(250..=u8::MAX);
+        assert_eq!(top.iter().collect::<Vec<_>>(), vec![240..=249]);
+        top.remove(0..=u8::MAX);
+        assert!(top.is_empty());
+    }
+
+    #[test]
+    fn test_locate_all() {
+        let ranges = RangeSet::from_iter([3..=5, 10..=14, 16..=20]);
+        let ids = [17, 1, 5, 32, 11, 8, 3];
+        assert_eq!(
+            ranges.locate_all(&ids),
+            vec![
+                (17, Some(16..=20)),
+                (1, None),
+                (5, Some(3..=5)),
+                (32, None),
+                (11, Some(10..=14)),
+                (8, None),
+                (3, Some(3..=5)),
+            ]
+        );
+        assert_eq!(ranges.contains_all(&ids), vec![17, 5, 11, 3]);
+        assert_eq!(ranges.contains_all(&[1, 3, 4, 15, 20]), vec![3, 4, 20]);
+    }
+
+    #[test]
+    fn test_count_overlap() {
+        let ranges = RangeSet::from_iter([3..=5, 10..=14, 16..=20]);
+        assert_eq!(ranges.count_overlap(0..=100), ranges.len());
+        assert_eq!(ranges.count_overlap(4..=12), 5);
+        assert_eq!(ranges.count_overlap(6..=9), 0);
+        assert_eq!(ranges.count_overlap(11..=11), 1);
+        assert_eq!(ranges.count_overlap(14..=16), 2);
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = 12..=11;
+        assert_eq!(ranges.count_overlap(empty), 0);
+    }
+
+    #[test]
+    fn test_text_round_trip() {
+        let ranges = RangeSet::from_iter([3..=5, 10..=14, 16..=20, 12..=18]);
+        assert_eq!(ranges.to_string(), "3-5\n10-20\n");
+        assert_eq!(ranges.to_string().parse::<RangeSet<usize>>(), Ok(ranges));
+        assert_eq!("".parse::<RangeSet<usize>>(), Ok(RangeSet::new()));
+    }
+
+    #[test]
+    fn test_text_validates() {
+        let err = |expected: &str| ParseError {
+            line: 2,
+            column: 1,
+            found: "4-6".to_owned(),
+            expected: expected.to_owned(),
+        };
+        let overlapping = "3-5\n4-6\n".parse::<RangeSet<usize>>();
+        assert_eq!(
+            overlapping,
+            Err(err("a range that starts after the previous one ends"))
+        );
+        let unordered = "10-12\n4-6\n".parse::<RangeSet<usize>>();
+        assert_eq!(
+            unordered,
+            Err(err("a range that starts after the previous one ends"))
+        );
+        let reversed = "1-2\n6-4\n".parse::<RangeSet<usize>>().unwrap_err();
+        assert_eq!(reversed.expected, "a range that ends after it starts");
+        let top = "1-2\n250-255\n".parse::<RangeSet<u8>>().unwrap_err();
+        assert_eq!(
+            (top.line, top.expected.as_str()),
+            (2, "a range that ends before the largest value")
+        );
+        assert_eq!("250-254".parse(), Ok(RangeSet::from_iter([250_u8..=254])));
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        let ranges = RangeSet::from_iter([3_u64..=5, 10..=200, 1 << 40..=1 << 41]);
+        let bytes = ranges.encode();
+        assert_eq!(bytes[..4], [3, 3, 4, 0xbf]);
+        assert_eq!(RangeSet::decode(&bytes), Ok(ranges));
+        assert_eq!(RangeSet::<u64>::decode(&[]), Ok(RangeSet::new()));
+        // deltas wider than usize
+        let ranges = RangeSet::from_iter([1_u128 << 100..=(1 << 100) + 5, 1 << 120..=1 << 121]);
+        assert_eq!(RangeSet::decode(&ranges.encode()), Ok(ranges));
+    }
+
+    #[test]
+    fn test_binary_validates() {
+        assert_eq!(RangeSet::<u64>::decode(&[3]), Err(DecodeError::Truncated));
+        assert_eq!(
+            RangeSet::<u64>::decode(&[3, 0x82]),
+            Err(DecodeError::Truncated)
+        );
+        assert_eq!(
+            RangeSet::<u64>::decode(&[3, 0]),
+            Err(DecodeError::Unordered(1))
+        );
+        assert_eq!(
+            RangeSet::<u8>::decode(&[0x7f, 0x7f, 0x7f]),
+            Err(DecodeError::Overflow(2))
+        );
+        // a delta past u64, then one past u128
+        let mut past_u64 = [0xff; 11];
+        past_u64[10] = 0x01;
+        assert_eq!(
+            RangeSet::<u64>::decode(&past_u64),
+            Err(DecodeError::Overflow(0))
+        );
+        assert_eq!(
+            RangeSet::<u128>::decode(&[0xff; 20]),
+            Err(DecodeError::Overflow(0))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_ops_match_model(ops in prop::collection::vec(op(), 0..32)) {
+            let mut ranges = RangeSet::new();
+            let mut model = BTreeSet::new();
+            for op in ops {
+                match op {
+                    Op::Add(range) => {
+                        ranges.add(range.clone());
+                        model.extend(range);
+                    }
+                    Op::Remove(range) => {
+                        ranges.remove(range.clone());
+                        model.retain(|elt| !range.contains(elt));
+                    }
+                    Op::Contains(elt) => {
+                        prop_assert_eq!(ranges.contains(elt), model.contains(&elt));
+                    }
+                }
+                assert_matches(&ranges, &model);
+            }
+        }
+
+        #[test]
+        fn prop_set_operations_match_model((a, a_model) in sets(), (b, b_model) in sets()) {
+            assert_matches(&a.union(&b), &a_model.union(&b_model).copied().collect());
+            assert_matches(
+                &a.intersection(&b),
+                &a_model.intersection(&b_model).copied().collect(),
+            );
+            assert_matches(
+                &a.difference(&b),
+                &a_model.difference(&b_model).copied().collect(),
+            );
+            let bounds = 0..=MAX;
+            assert_matches(
+                &a.complement(bounds.clone()),
+                &bounds.filter(|elt| !a_model.contains(elt)).collect(),
+            );
+        }
+
+        #[test]
+        fn prop_queries_match_model(
+            (ranges, model) in sets(),
+            elts in prop::collection::vec(0..MAX + 8, 0..16),
+            query in range(),
+        ) {
+            for (elt, range) in ranges.locate_all(&elts) {
+                match range {
+                    Some(range) => {
+                        prop_assert!(range.contains(&elt));
+                        prop_assert!(ranges.iter().contains(&range));
+                    }
+                    None => prop_assert!(!model.contains(&elt)),
+                }
+            }
+            let fresh = elts.iter().copied().filter(|elt| model.contains(elt)).collect_vec();
+            prop_assert_eq!(ranges.contains_all(&elts), fresh);
+            let overlap = model.range(query.clone()).count() as u128;
+            prop_assert_eq!(ranges.count_overlap(query), overlap);
+        }
+
+        #[test]
+        fn prop_encodings_round_trip((ranges, _) in sets(), offset in 0..MAX as i64) {
+            // shifted so that some bounds are negative
+            let signed = RangeSet::from_iter(
+                ranges.iter().map(|r| *r.start() as i64 - offset..=*r.end() as i64 - offset),
+            );
+            prop_assert_eq!(ranges.to_string().parse::<RangeSet<usize>>(), Ok(ranges.clone()));
+            prop_assert_eq!(signed.to_string().parse::<RangeSet<i64>>(), Ok(signed.clone()));
+            prop_assert_eq!(RangeSet::decode(&ranges.encode()), Ok(ranges));
+            prop_assert_eq!(RangeSet::decode(&signed.encode()), Ok(signed));
+        }
+    }
+}
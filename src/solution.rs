@@ -0,0 +1,13 @@
+use std::fmt::Display;
+
+use crate::parse::ParseError;
+
+/// one day's puzzle, split into parsing the input and solving each part of it
+pub trait Solution {
+    type Input;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer;
+    fn part2(input: &Self::Input) -> Self::Answer;
+}
@@ -0,0 +1,68 @@
+use std::collections::{HashMap, HashSet};
+
+pub struct UnionFind(HashMap<usize, usize>);
+
+impl UnionFind {
+    pub fn new(size: usize) -> Self {
+        Self((0..size).map(|i| (i, i)).collect())
+    }
+
+    /// also compacts, as an (unnecessary) optimization
+    pub fn peek(&mut self, x: usize) -> usize {
+        let next = self.0[&x];
+        if next == x {
+            x
+        } else {
+            let peeked = self.peek(next);
+            // compaction for optimization
+            self.0.insert(x, peeked);
+            peeked
+        }
+    }
+
+    pub fn unify(&mut self, a: usize, b: usize) {
+        let peeked_a = self.peek(a);
+        let peeked_b = self.peek(b);
+        debug_assert_eq!(self.0[&peeked_a], peeked_a);
+        debug_assert_eq!(self.0[&peeked_b], peeked_b);
+        self.0.insert(peeked_a, peeked_b);
+    }
+
+    /// sizes of each equivalence class, in no particular order
+    pub fn classes(&mut self) -> Vec<usize> {
+        let mut classes: HashMap<usize, usize> = HashMap::new();
+        for i in 0..self.0.len() {
+            let peeked = self.peek(i);
+            *classes.entry(peeked).or_default() += 1
+        }
+        classes.values().copied().collect()
+    }
+
+    pub fn n_classes(&mut self) -> usize {
+        let mut classes = HashSet::new();
+        for i in 0..self.0.len() {
+            let peeked = self.peek(i);
+            classes.insert(peeked);
+        }
+        classes.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::UnionFind;
+
+    #[test]
+    fn test_unify() {
+        let mut union_find = UnionFind::new(5);
+        assert_eq!(union_find.n_classes(), 5);
+        union_find.unify(0, 1);
+        union_find.unify(3, 1);
+        assert_eq!(union_find.peek(0), union_find.peek(3));
+        assert_ne!(union_find.peek(0), union_find.peek(2));
+        assert_eq!(union_find.n_classes(), 3);
+        let mut classes = union_find.classes();
+        classes.sort();
+        assert_eq!(classes, vec![1, 1, 3]);
+    }
+}
@@ -0,0 +1,47 @@
+//! runs the `aoc` binary on small inputs, checking what it prints
+
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+/// runs `aoc` with `args`, feeding it `stdin`, and returns what it printed
+fn aoc(args: &[&str], stdin: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "aoc {args:?} failed: {stderr}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn ids_dedupe_ranges() {
+    let list = [
+        "ids",
+        "--int",
+        "u64",
+        "--dedupe-ranges",
+        "--list",
+        "--input",
+        "-",
+    ];
+    assert_eq!(
+        aoc(&list, "11-22,20-30"),
+        "id  period  repetitions\n11       1            2\n22       1            2\n"
+    );
+    // merging copes with a range that ends at the largest u64
+    let top = "18446744073709551600-18446744073709551615,18446744073709551610-18446744073709551612";
+    assert_eq!(aoc(&list, top), "id  period  repetitions\n");
+}
@@ -0,0 +1,97 @@
+//! runs every day's solver against the puzzle examples in `fixtures/`,
+//! checking them against `fixtures/answers.txt`
+
+use std::path::Path;
+
+use advent_of_code_2025::{
+    answers::Answers,
+    days::{
+        day01::Day01, day02::Day02, day03::Day03, day04::Day04, day05::Day05, day06::Day06,
+        day07::Day07, day08::Day08, day09::Day09, day10::Day10, day11::Day11,
+    },
+    inputs::example_path,
+    solution::Solution,
+};
+
+fn fixtures() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"))
+}
+
+fn example(day: u8, part: usize) -> String {
+    let path = example_path(fixtures(), day, part);
+    std::fs::read_to_string(&path).unwrap_or_else(|err| panic!("{}: {err}", path.display()))
+}
+
+fn check<S: Solution>(day: u8) {
+    let answers: Answers = std::fs::read_to_string(fixtures().join("answers.txt"))
+        .unwrap()
+        .parse()
+        .unwrap();
+    let solve = [
+        |input: &S::Input| S::part1(input).to_string(),
+        |input: &S::Input| S::part2(input).to_string(),
+    ];
+    for (part, solve) in (1..).zip(solve) {
+        let expected = answers
+            .get(day, part)
+            .unwrap_or_else(|| panic!("no expected answer for day {day} part {part}"));
+        let input = S::parse(&example(day, part)).unwrap();
+        assert_eq!(solve(&input), expected, "day {day} part {part}");
+    }
+}
+
+#[test]
+fn day01() {
+    check::<Day01>(1);
+}
+
+#[test]
+fn day02() {
+    check::<Day02>(2);
+}
+
+#[test]
+fn day03() {
+    check::<Day03>(3);
+}
+
+#[test]
+fn day04() {
+    check::<Day04>(4);
+}
+
+#[test]
+fn day05() {
+    check::<Day05>(5);
+}
+
+#[test]
+fn day06() {
+    check::<Day06>(6);
+}
+
+#[test]
+fn day07() {
+    check::<Day07>(7);
+}
+
+#[test]
+fn day08() {
+    // the example connects only the 10 closest pairs
+    check::<Day08<10>>(8);
+}
+
+#[test]
+fn day09() {
+    check::<Day09>(9);
+}
+
+#[test]
+fn day10() {
+    check::<Day10>(10);
+}
+
+#[test]
+fn day11() {
+    check::<Day11>(11);
+}